@@ -0,0 +1,236 @@
+use std::sync::Arc;
+
+use arrow::array::{Array, ArrayRef, AsArray, Float64Array, UInt64Array};
+use arrow::compute;
+use arrow::datatypes::{DataType, Float64Type};
+
+use crate::array::{check_same_length, CellIndexArray};
+use crate::error::Error;
+
+/// Aggregation applied to the values of all rows sharing the same cell.
+///
+/// Null values are skipped by all aggregations.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Agg {
+    /// Number of non-null values as `UInt64`.
+    Count,
+
+    /// Sum of the values as `Float64`.
+    ///
+    /// Integer values are summed as `f64` as well, so sums exceeding 2^53 lose precision.
+    Sum,
+
+    /// Arithmetic mean of the values as `Float64`.
+    Mean,
+
+    /// Minimum of the values as `Float64`.
+    Min,
+
+    /// Maximum of the values as `Float64`.
+    Max,
+
+    /// First non-null value in row order, keeping the data type of the input.
+    First,
+}
+
+/// Result of [`aggregate_by_cell`].
+pub struct CellAggregation {
+    /// The distinct non-null cells, sorted ascending.
+    pub cells: CellIndexArray,
+
+    /// One array per requested aggregation, in the order of the requests. The values are
+    /// null for cells without any non-null input value, except for [`Agg::Count`].
+    pub arrays: Vec<ArrayRef>,
+}
+
+/// Group the rows by their cell and aggregate the given arrays for each distinct cell.
+///
+/// Rows with a null cell are skipped. [`Agg::Sum`], [`Agg::Mean`], [`Agg::Min`] and [`Agg::Max`]
+/// require numeric arrays.
+pub fn aggregate_by_cell(
+    cells: &CellIndexArray,
+    columns: &[(&dyn Array, Agg)],
+) -> Result<CellAggregation, Error> {
+    #[cfg(feature = "rayon")]
+    use rayon::prelude::{IntoParallelRefIterator, ParallelIterator};
+
+    for (array, _) in columns {
+        check_same_length(cells.len(), array.len())?;
+    }
+
+    let unique_cells = cells.dedup_sorted();
+    let row_groups = row_groups(cells, unique_cells.primitive_array().values());
+
+    #[cfg(not(feature = "rayon"))]
+    let columns_iter = columns.iter();
+
+    #[cfg(feature = "rayon")]
+    let columns_iter = columns.par_iter();
+
+    let arrays = columns_iter
+        .map(|(array, agg)| aggregate_array(*array, *agg, &row_groups, unique_cells.len()))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(CellAggregation {
+        cells: unique_cells,
+        arrays,
+    })
+}
+
+/// The group of each row as the position of its cell in `unique_cells`, `None` for rows
+/// with a null cell.
+fn row_groups(cells: &CellIndexArray, unique_cells: &[u64]) -> Vec<Option<usize>> {
+    cells
+        .primitive_array()
+        .iter()
+        .map(|h3index| {
+            h3index.map(|h3index| {
+                unique_cells
+                    .binary_search(&h3index)
+                    .expect("all cells are contained in unique_cells")
+            })
+        })
+        .collect()
+}
+
+fn aggregate_array(
+    array: &dyn Array,
+    agg: Agg,
+    row_groups: &[Option<usize>],
+    num_groups: usize,
+) -> Result<ArrayRef, Error> {
+    // rows with a non-null cell and a non-null value
+    let valid_rows = || {
+        row_groups
+            .iter()
+            .enumerate()
+            .filter(|(row_i, _)| array.is_valid(*row_i))
+            .filter_map(|(row_i, group_i)| group_i.map(|group_i| (row_i, group_i)))
+    };
+
+    let aggregated: ArrayRef = match agg {
+        Agg::Count => {
+            let mut counts = vec![0u64; num_groups];
+            for (_, group_i) in valid_rows() {
+                counts[group_i] += 1;
+            }
+            Arc::new(UInt64Array::from(counts))
+        }
+        Agg::First => {
+            let mut first_rows = vec![None; num_groups];
+            for (row_i, group_i) in valid_rows() {
+                first_rows[group_i].get_or_insert(row_i as u64);
+            }
+            compute::take(array, &UInt64Array::from(first_rows), None)?
+        }
+        Agg::Sum | Agg::Mean | Agg::Min | Agg::Max => {
+            if !array.data_type().is_numeric() {
+                return Err(Error::UnsupportedDataType(array.data_type().clone()));
+            }
+            let values = compute::cast(array, &DataType::Float64)?;
+            let values = values.as_primitive::<Float64Type>();
+
+            // aggregated value and number of values
+            let mut accumulated: Vec<Option<(f64, u64)>> = vec![None; num_groups];
+            for (row_i, group_i) in valid_rows() {
+                let value = values.value(row_i);
+                match &mut accumulated[group_i] {
+                    Some((aggregated, count)) => {
+                        *aggregated = match agg {
+                            Agg::Min => aggregated.min(value),
+                            Agg::Max => aggregated.max(value),
+                            _ => *aggregated + value,
+                        };
+                        *count += 1;
+                    }
+                    acc @ None => *acc = Some((value, 1)),
+                }
+            }
+
+            Arc::new(
+                accumulated
+                    .into_iter()
+                    .map(|acc| {
+                        acc.map(|(aggregated, count)| {
+                            if agg == Agg::Mean {
+                                aggregated / count as f64
+                            } else {
+                                aggregated
+                            }
+                        })
+                    })
+                    .collect::<Float64Array>(),
+            )
+        }
+    };
+    Ok(aggregated)
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow::array::{Array, AsArray, Int32Array, StringArray};
+    use arrow::datatypes::{Float64Type, UInt64Type};
+    use h3o::{LatLng, Resolution};
+
+    use crate::algorithm::{aggregate_by_cell, Agg};
+    use crate::array::CellIndexArray;
+
+    #[test]
+    fn aggregate() {
+        let cell_a = LatLng::new(23.4, 12.4).unwrap().to_cell(Resolution::Five);
+        let cell_b = LatLng::new(12.3, 0.5).unwrap().to_cell(Resolution::Five);
+        let cells: CellIndexArray =
+            vec![Some(cell_a), Some(cell_b), None, Some(cell_a), Some(cell_b)].into();
+        let numbers = Int32Array::from(vec![Some(1), None, Some(100), Some(4), None]);
+        let names = StringArray::from(vec![None, Some("b1"), Some("x"), Some("a2"), Some("b2")]);
+
+        let aggregated = aggregate_by_cell(
+            &cells,
+            &[
+                (&numbers, Agg::Count),
+                (&numbers, Agg::Sum),
+                (&numbers, Agg::Mean),
+                (&numbers, Agg::Min),
+                (&numbers, Agg::Max),
+                (&names, Agg::First),
+            ],
+        )
+        .unwrap();
+
+        let mut expected_cells = vec![cell_a, cell_b];
+        expected_cells.sort_unstable();
+        assert_eq!(
+            aggregated.cells.iter().flatten().collect::<Vec<_>>(),
+            expected_cells
+        );
+        let a = expected_cells.iter().position(|c| *c == cell_a).unwrap();
+        let b = 1 - a;
+
+        let counts = aggregated.arrays[0].as_primitive::<UInt64Type>();
+        assert_eq!(counts.value(a), 2);
+        assert_eq!(counts.value(b), 0);
+
+        for (array_i, expected) in [(1, 5.0), (2, 2.5), (3, 1.0), (4, 4.0)] {
+            let values = aggregated.arrays[array_i].as_primitive::<Float64Type>();
+            assert_eq!(values.value(a), expected);
+            assert!(values.is_null(b));
+        }
+
+        let first_names = aggregated.arrays[5].as_string::<i32>();
+        assert_eq!(first_names.value(a), "a2");
+        assert_eq!(first_names.value(b), "b1");
+    }
+
+    #[test]
+    fn invalid_input() {
+        let cell = LatLng::new(23.4, 12.4).unwrap().to_cell(Resolution::Five);
+        let cells: CellIndexArray = vec![cell, cell].into();
+
+        let numbers = Int32Array::from(vec![1]);
+        assert!(aggregate_by_cell(&cells, &[(&numbers, Agg::Sum)]).is_err());
+
+        let names = StringArray::from(vec!["a", "b"]);
+        assert!(aggregate_by_cell(&cells, &[(&names, Agg::Sum)]).is_err());
+        assert!(aggregate_by_cell(&cells, &[(&names, Agg::Count)]).is_ok());
+    }
+}
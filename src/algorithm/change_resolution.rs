@@ -109,6 +109,7 @@ impl ChangeResolutionOp for CellIndexArray {
         self.iter().flatten().for_each(|cell| {
             let len_before = after_vec.len();
             extend_with_cell(&mut after_vec, cell, resolution);
+            #[allow(clippy::manual_repeat_n)]
             before_vec.extend(repeat(cell).take(after_vec.len() - len_before));
         });
 
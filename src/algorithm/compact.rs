@@ -35,16 +35,24 @@ impl CompactOp for CellIndexArray {
     }
 }
 
-struct CellSet {
+/// A set of cells of mixed resolutions, grouped by their resolution.
+///
+/// Cells can be added incrementally. Calling [`CellSet::compact`] or [`CellSet::finalize`]
+/// removes duplicates as well as cells whose ancestors are contained in the set.
+pub struct CellSet {
+    /// resolutions whose cells may be unsorted or contain duplicates
     pub(crate) modified_resolutions: [bool; 16],
 
+    /// resolutions which received cells since the last compaction
+    pub(crate) uncompacted_resolutions: [bool; 16],
+
     /// cells by their resolution. The index of the array is the resolution for the referenced vec
     pub(crate) cells_by_resolution: [Vec<CellIndex>; 16],
 }
 
 impl CellSet {
-    #[allow(unused)]
-    pub(crate) fn append(&mut self, other: &mut Self) {
+    /// Move all cells of `other` into `self`, leaving `other` empty.
+    pub fn append(&mut self, other: &mut Self) {
         for ((r_idx, sink), source) in self
             .cells_by_resolution
             .iter_mut()
@@ -55,15 +63,17 @@ impl CellSet {
                 continue;
             }
             self.modified_resolutions[r_idx] = true;
+            self.uncompacted_resolutions[r_idx] = true;
             sink.append(source);
         }
     }
 
-    pub(crate) fn compact(&mut self) -> Result<(), Error> {
+    /// Compact the contained cells.
+    pub fn compact(&mut self) -> Result<(), Error> {
         self.dedup(false, false);
 
         if let Some((min_touched_res, _)) = self
-            .modified_resolutions
+            .uncompacted_resolutions
             .iter()
             .enumerate()
             .rev()
@@ -76,14 +86,14 @@ impl CellSet {
                 let mut compacted_in = std::mem::take(&mut self.cells_by_resolution[r_idx]);
                 compacted_in.sort_unstable();
                 compacted_in.dedup();
-                for cell in CellIndex::compact(compacted_in.into_iter())? {
+                for cell in CellIndex::compact(compacted_in)? {
                     self.insert(cell);
                 }
                 res = h3_res.pred();
             }
 
-            // mark all resolutions as not-modified
-            self.modified_resolutions
+            // mark all resolutions as compacted
+            self.uncompacted_resolutions
                 .iter_mut()
                 .for_each(|r| *r = false);
         }
@@ -102,7 +112,6 @@ impl CellSet {
         )
     }
 
-    #[allow(unused)]
     pub fn iter_uncompacted(&self, r: Resolution) -> Box<dyn Iterator<Item = CellIndex> + '_> {
         let r_idx: usize = r.into();
         Box::new((0..=r_idx).flat_map(move |r_idx| {
@@ -112,20 +121,88 @@ impl CellSet {
         }))
     }
 
-    #[allow(unused)]
     pub fn len(&self) -> usize {
         self.cells_by_resolution.iter().map(|v| v.len()).sum()
     }
 
-    #[allow(unused)]
     pub fn is_empty(&self) -> bool {
         !self.cells_by_resolution.iter().any(|v| !v.is_empty())
     }
 
-    pub(crate) fn insert(&mut self, cell: CellIndex) {
+    pub fn insert(&mut self, cell: CellIndex) {
         let idx: usize = cell.resolution().into();
         self.cells_by_resolution[idx].push(cell);
         self.modified_resolutions[idx] = true;
+        self.uncompacted_resolutions[idx] = true;
+    }
+
+    /// Returns true when `cell` or any of its ancestors is contained in the set.
+    pub fn contains(&self, cell: CellIndex) -> bool {
+        let mut r = Some(cell.resolution());
+        while let Some(resolution) = r {
+            let r_idx: usize = resolution.into();
+            if let Some(parent) = cell.parent(resolution) {
+                let cells = &self.cells_by_resolution[r_idx];
+                let found = if self.modified_resolutions[r_idx] {
+                    cells.contains(&parent)
+                } else {
+                    // unmodified resolutions are sorted
+                    cells.binary_search(&parent).is_ok()
+                };
+                if found {
+                    return true;
+                }
+            }
+            r = resolution.pred();
+        }
+        false
+    }
+
+    /// Remove `cell` from the set.
+    ///
+    /// All descendants of `cell` are removed as well. When an ancestor of `cell`
+    /// is contained in the set, the ancestor is replaced by its descendants not
+    /// covering `cell`.
+    pub fn remove(&mut self, cell: CellIndex) {
+        let cell_resolution = cell.resolution();
+        let cell_r_idx: usize = cell_resolution.into();
+
+        // remove the cell itself and its descendants
+        // `retain` keeps the order, so sorted resolutions stay sorted.
+        for cells in self.cells_by_resolution[cell_r_idx..].iter_mut() {
+            cells.retain(|c| c.parent(cell_resolution) != Some(cell));
+        }
+
+        // split up the ancestors
+        let mut r = cell_resolution.pred();
+        while let Some(resolution) = r {
+            let r_idx: usize = resolution.into();
+            let Some(ancestor) = cell.parent(resolution) else {
+                break;
+            };
+            let cells = &mut self.cells_by_resolution[r_idx];
+            let len_before = cells.len();
+            // the set may hold multiple copies of the ancestor until it gets deduplicated
+            cells.retain(|c| *c != ancestor);
+            if cells.len() != len_before {
+                // re-add the siblings along the path from the ancestor down to `cell`
+                let mut path_parent = ancestor;
+                let mut path_resolution = resolution.succ();
+                while let Some(child_resolution) =
+                    path_resolution.filter(|pr| *pr <= cell_resolution)
+                {
+                    let path_cell = cell.parent(child_resolution).expect("coarser resolution");
+                    for sibling in path_parent.children(child_resolution) {
+                        if sibling != path_cell {
+                            self.insert(sibling);
+                        }
+                    }
+                    path_parent = path_cell;
+                    path_resolution = child_resolution.succ();
+                }
+            }
+            r = resolution.pred();
+        }
     }
 
     pub(crate) fn dedup(&mut self, shrink: bool, parents: bool) {
@@ -150,6 +227,11 @@ impl CellSet {
             dedup_vec(v, shrink);
         });
 
+        // all resolutions are sorted and free of duplicates now
+        self.modified_resolutions
+            .iter_mut()
+            .for_each(|r| *r = false);
+
         if parents
             && self
                 .cells_by_resolution
@@ -182,7 +264,9 @@ impl CellSet {
         }
     }
 
-    pub(crate) fn finalize(&mut self, compact: bool) -> Result<(), Error> {
+    /// Remove duplicates and cells whose ancestors are contained in the set. The cells
+    /// are compacted when `compact` is set.
+    pub fn finalize(&mut self, compact: bool) -> Result<(), Error> {
         if compact {
             self.compact()?;
         } else {
@@ -197,7 +281,151 @@ impl Default for CellSet {
     fn default() -> Self {
         Self {
             modified_resolutions: [false; 16],
+            uncompacted_resolutions: [false; 16],
             cells_by_resolution: Default::default(),
         }
     }
 }
+
+impl Extend<CellIndex> for CellSet {
+    fn extend<T: IntoIterator<Item = CellIndex>>(&mut self, iter: T) {
+        iter.into_iter().for_each(|cell| self.insert(cell));
+    }
+}
+
+impl FromIterator<CellIndex> for CellSet {
+    fn from_iter<T: IntoIterator<Item = CellIndex>>(iter: T) -> Self {
+        let mut cellset = Self::default();
+        cellset.extend(iter);
+        cellset
+    }
+}
+
+impl CellSet {
+    /// Add all valid cells of `array` to the set.
+    pub fn extend_from_cellindexarray(&mut self, array: &CellIndexArray) {
+        self.extend(array.iter().flatten())
+    }
+
+    /// Convert to a `CellIndexArray` containing the cells of the set as they are stored.
+    ///
+    /// Call [`CellSet::compact`] before to obtain a compacted array.
+    pub fn to_cellindexarray(&self) -> CellIndexArray {
+        self.iter_compacted().collect()
+    }
+
+    /// Convert to a `CellIndexArray` containing all cells of the set uncompacted to resolution `r`.
+    ///
+    /// Cells of resolutions finer than `r` are omitted.
+    pub fn to_cellindexarray_uncompacted(&self, r: Resolution) -> CellIndexArray {
+        self.iter_uncompacted(r).collect()
+    }
+}
+
+impl From<&CellIndexArray> for CellSet {
+    fn from(value: &CellIndexArray) -> Self {
+        let mut cellset = Self::default();
+        cellset.extend_from_cellindexarray(value);
+        cellset
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::algorithm::CellSet;
+    use crate::array::CellIndexArray;
+    use h3o::{LatLng, Resolution};
+
+    #[test]
+    fn cellset_contains() {
+        let cell = LatLng::new(23.4, 12.4).unwrap().to_cell(Resolution::Five);
+        let child = cell.children(Resolution::Seven).next().unwrap();
+        let other = LatLng::new(12.3, 0.5).unwrap().to_cell(Resolution::Seven);
+
+        let mut cellset = CellSet::from(&CellIndexArray::from(vec![cell]));
+        assert!(cellset.contains(cell));
+        assert!(cellset.contains(child));
+        assert!(!cellset.contains(other));
+
+        cellset.finalize(true).unwrap();
+        assert!(cellset.contains(child));
+        assert!(!cellset.contains(cell.parent(Resolution::Four).unwrap()));
+    }
+
+    #[test]
+    fn cellset_finalize_keeps_sorted_state() {
+        let cell = LatLng::new(23.4, 12.4).unwrap().to_cell(Resolution::Five);
+        let children: Vec<_> = cell.children(Resolution::Six).collect();
+
+        let mut cellset: CellSet = children.iter().rev().copied().collect();
+        cellset.finalize(false).unwrap();
+        assert!(cellset.modified_resolutions.iter().all(|m| !m));
+        assert!(children.iter().all(|child| cellset.contains(*child)));
+
+        cellset.remove(children[6].children(Resolution::Seven).next().unwrap());
+        cellset.remove(children[0]);
+        assert!(!cellset.modified_resolutions[6]);
+        assert!(!cellset.contains(children[0]));
+        assert!(cellset.contains(children[1]));
+
+        // finalizing without compaction does not prevent a later compaction
+        let mut cellset: CellSet = children.iter().copied().collect();
+        cellset.finalize(false).unwrap();
+        cellset.compact().unwrap();
+        assert_eq!(
+            cellset
+                .to_cellindexarray()
+                .iter()
+                .flatten()
+                .collect::<Vec<_>>(),
+            vec![cell]
+        );
+    }
+
+    #[test]
+    fn cellset_remove() {
+        let cell = LatLng::new(23.4, 12.4).unwrap().to_cell(Resolution::Five);
+        let child = cell.children(Resolution::Seven).nth(10).unwrap();
+
+        let mut cellset: CellSet = [cell].into_iter().collect();
+        cellset.remove(child);
+        assert!(!cellset.contains(child));
+        assert!(!cellset.contains(cell));
+        assert_eq!(
+            cellset
+                .to_cellindexarray_uncompacted(Resolution::Seven)
+                .len(),
+            49 - 1
+        );
+
+        cellset.extend([child]);
+        cellset.compact().unwrap();
+        assert_eq!(cellset.to_cellindexarray().len(), 1);
+        assert!(cellset.contains(cell));
+
+        cellset.remove(cell);
+        assert!(cellset.is_empty());
+    }
+
+    #[test]
+    fn cellset_remove_duplicated_ancestor() {
+        let cell = LatLng::new(23.4, 12.4).unwrap().to_cell(Resolution::Five);
+        let child = cell.children(Resolution::Seven).nth(10).unwrap();
+
+        let mut cellset = CellSet::default();
+        cellset.insert(cell);
+        cellset.insert(cell);
+        let mut other: CellSet = [cell].into_iter().collect();
+        cellset.append(&mut other);
+
+        cellset.remove(child);
+        assert!(!cellset.contains(child));
+        assert!(!cellset.contains(cell));
+        assert_eq!(
+            cellset
+                .to_cellindexarray_uncompacted(Resolution::Seven)
+                .len(),
+            49 - 1
+        );
+    }
+}
@@ -1,13 +1,14 @@
-use crate::array::{CellIndexArray, H3Array, H3ListArray, H3ListArrayBuilder};
+use crate::array::{check_same_length, CellIndexArray, H3Array, H3ListArray, H3ListArrayBuilder};
 use crate::error::Error;
 use ahash::{HashMap, HashMapExt};
 use arrow::array::{
-    Array, GenericListArray, GenericListBuilder, OffsetSizeTrait, PrimitiveArray, UInt32Array,
-    UInt32Builder,
+    Array, Float64Array, GenericListArray, GenericListBuilder, Int32Array, OffsetSizeTrait,
+    PrimitiveArray, UInt32Array, UInt32Builder,
 };
 use h3o::{max_grid_disk_size, CellIndex};
 use std::cmp::{max, min};
 use std::collections::hash_map::Entry;
+use std::sync::Arc;
 
 pub struct GridDiskDistances<O: OffsetSizeTrait> {
     pub cells: H3ListArray<CellIndex, O>,
@@ -25,6 +26,61 @@ pub struct GridDiskAggregateK {
     pub distances: UInt32Array,
 }
 
+/// Distance-decay kernel used to weight values spread over a grid disk.
+#[derive(Clone)]
+pub enum SmoothingKernel {
+    /// All cells of the disk have the weight `1`.
+    Uniform,
+
+    /// The weight decreases linearly from `1` at the center to `1 / (k + 1)` at the edge of
+    /// the disk.
+    Linear,
+
+    /// Gaussian decay with the standard deviation `sigma`, given in grid distances.
+    Gaussian { sigma: f64 },
+
+    /// Custom function returning the weight for the grid distance to the center.
+    Custom(Arc<dyn Fn(u32) -> f64 + Send + Sync>),
+}
+
+impl SmoothingKernel {
+    /// The weight of a cell at `grid_distance` from the center of a disk of radius `k`.
+    pub fn weight(&self, grid_distance: u32, k: u32) -> f64 {
+        match self {
+            Self::Uniform => 1.0,
+            Self::Linear => 1.0 - f64::from(grid_distance) / f64::from(k + 1),
+            Self::Gaussian { sigma } => {
+                (-f64::from(grid_distance).powi(2) / (2.0 * sigma.powi(2))).exp()
+            }
+            Self::Custom(weight_fn) => weight_fn(grid_distance),
+        }
+    }
+
+    fn validate(&self) -> Result<(), Error> {
+        match self {
+            Self::Gaussian { sigma } if !(sigma.is_finite() && *sigma > 0.0) => {
+                Err(Error::InvalidKernelSigma(*sigma))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum SmoothingAggregation {
+    /// Sum of the weighted values.
+    Sum,
+
+    /// Weighted mean: the sum of the weighted values divided by the sum of the weights.
+    /// Null when the weights sum up to zero.
+    Mean,
+}
+
+pub struct GridDiskSmoothed {
+    pub cells: CellIndexArray,
+    pub values: Float64Array,
+}
+
 pub trait GridOp
 where
     Self: Sized,
@@ -44,6 +100,35 @@ where
         k: u32,
         k_agg_method: KAggregationMethod,
     ) -> Result<GridDiskAggregateK, Error>;
+
+    /// Spread each of the `values` to the grid disk of radius `k` around the cell at the same
+    /// position, weighted by the `kernel`, and aggregate the weighted values of each reached cell.
+    ///
+    /// Null cells and null values are skipped. The resulting cells are sorted ascending.
+    ///
+    /// Fails when the `sigma` of a [`SmoothingKernel::Gaussian`] is not a positive, finite number.
+    fn grid_disk_smooth(
+        &self,
+        values: &Float64Array,
+        k: u32,
+        kernel: &SmoothingKernel,
+        aggregation: SmoothingAggregation,
+    ) -> Result<GridDiskSmoothed, Error>;
+
+    /// The grid distance between each cell and the cell at the same position in `other`.
+    ///
+    /// The value is null when the distance can not be computed, for example because the cells
+    /// are too far apart or are separated by pentagon distortion.
+    fn grid_distance(&self, other: &Self) -> Result<Int32Array, Error>;
+
+    /// The cells of the line between each cell and the cell at the same position in `other`,
+    /// including both of them.
+    ///
+    /// The list is null when the line can not be computed.
+    fn grid_path_cells<O: OffsetSizeTrait>(
+        &self,
+        other: &Self,
+    ) -> Result<H3ListArray<CellIndex, O>, Error>;
 }
 
 impl GridOp for H3Array<CellIndex> {
@@ -53,18 +138,19 @@ impl GridOp for H3Array<CellIndex> {
             self.len() * max_grid_disk_size(k) as usize,
         );
 
-        for cell in self.iter() {
-            match cell {
-                Some(cell) => {
-                    let disc: Vec<_> = cell.grid_disk(k);
-                    builder.values().append_many(disc);
+        for_each_mapped_cell(
+            self,
+            |cell| cell.grid_disk::<Vec<_>>(k),
+            |disk| match disk {
+                Some(disk) => {
+                    builder.values().append_many(disk);
                     builder.append(true);
                 }
                 None => {
                     builder.append(false);
                 }
-            }
-        }
+            },
+        );
         builder.finish()
     }
 
@@ -83,41 +169,144 @@ impl GridOp for H3Array<CellIndex> {
         build_grid_disk(self, k_max, |_, k| k >= k_min)
     }
 
+    /// The resulting cells are sorted ascending.
     fn grid_disk_aggregate_k(
         &self,
         k: u32,
         k_agg_method: KAggregationMethod,
     ) -> Result<GridDiskAggregateK, Error> {
-        let mut cellmap: HashMap<CellIndex, u32> = HashMap::with_capacity(self.len());
-        for cell in self.iter().flatten() {
-            for (grid_cell, grid_distance) in cell.grid_disk_distances::<Vec<_>>(k).into_iter() {
-                match cellmap.entry(grid_cell) {
-                    Entry::Occupied(mut e) => {
-                        e.insert(match k_agg_method {
-                            KAggregationMethod::Min => min(*e.get(), grid_distance),
-                            KAggregationMethod::Max => max(*e.get(), grid_distance),
-                        });
-                    }
-                    Entry::Vacant(e) => {
-                        e.insert(grid_distance);
-                    }
-                };
-            }
-        }
-
-        let mut cells = Vec::with_capacity(cellmap.len());
-        let mut distances = Vec::with_capacity(cellmap.len());
-
-        for (cell, distance) in cellmap.into_iter() {
-            cells.push(cell);
-            distances.push(distance);
-        }
+        let cellmap = fold_grid_disks(
+            self,
+            k,
+            |_| true,
+            |_, grid_distance| grid_distance,
+            |aggregated, grid_distance| {
+                *aggregated = match k_agg_method {
+                    KAggregationMethod::Min => min(*aggregated, grid_distance),
+                    KAggregationMethod::Max => max(*aggregated, grid_distance),
+                }
+            },
+        );
 
+        let (cells, distances): (Vec<_>, Vec<_>) = into_sorted_vec(cellmap).into_iter().unzip();
         Ok(GridDiskAggregateK {
             cells: CellIndexArray::from(cells),
             distances: PrimitiveArray::new(distances.into(), None),
         })
     }
+
+    fn grid_disk_smooth(
+        &self,
+        values: &Float64Array,
+        k: u32,
+        kernel: &SmoothingKernel,
+        aggregation: SmoothingAggregation,
+    ) -> Result<GridDiskSmoothed, Error> {
+        check_same_length(self.len(), values.len())?;
+        kernel.validate()?;
+
+        // sums of the weighted values and of the weights
+        let cellmap = fold_grid_disks(
+            self,
+            k,
+            |row_i| values.is_valid(row_i),
+            |row_i, grid_distance| {
+                let weight = kernel.weight(grid_distance, k);
+                (values.value(row_i) * weight, weight)
+            },
+            |(value_sum, weight_sum), (value, weight)| {
+                *value_sum += value;
+                *weight_sum += weight;
+            },
+        );
+
+        let (cells, values): (Vec<_>, Vec<_>) = into_sorted_vec(cellmap)
+            .into_iter()
+            .map(|(cell, (value_sum, weight_sum))| {
+                let value = match aggregation {
+                    SmoothingAggregation::Sum => Some(value_sum),
+                    SmoothingAggregation::Mean => {
+                        (weight_sum != 0.0).then_some(value_sum / weight_sum)
+                    }
+                };
+                (cell, value)
+            })
+            .unzip();
+        Ok(GridDiskSmoothed {
+            cells: CellIndexArray::from(cells),
+            values: values.into(),
+        })
+    }
+
+    fn grid_distance(&self, other: &Self) -> Result<Int32Array, Error> {
+        check_same_length(self.len(), other.len())?;
+        Ok(self
+            .iter()
+            .zip(other.iter())
+            .map(|(cell, other_cell)| match (cell, other_cell) {
+                (Some(cell), Some(other_cell)) => cell.grid_distance(other_cell).ok(),
+                _ => None,
+            })
+            .collect())
+    }
+
+    fn grid_path_cells<O: OffsetSizeTrait>(
+        &self,
+        other: &Self,
+    ) -> Result<H3ListArray<CellIndex, O>, Error> {
+        check_same_length(self.len(), other.len())?;
+        Ok(self
+            .iter()
+            .zip(other.iter())
+            .map(|(cell, other_cell)| match (cell, other_cell) {
+                (Some(cell), Some(other_cell)) => cell
+                    .grid_path_cells(other_cell)
+                    .ok()
+                    .and_then(|path| path.collect::<Result<Vec<_>, _>>().ok()),
+                _ => None,
+            })
+            .collect())
+    }
+}
+
+/// Number of rows mapped in parallel before the results are passed on, bounding the
+/// memory used for buffering.
+#[cfg(feature = "rayon")]
+const MAP_CHUNK_SIZE: usize = 4096;
+
+/// Apply `f` to all non-null cells and pass the results to `sink` in the order of the rows.
+#[cfg(not(feature = "rayon"))]
+fn for_each_mapped_cell<T, F, S>(cellindexarray: &CellIndexArray, f: F, mut sink: S)
+where
+    F: Fn(CellIndex) -> T,
+    S: FnMut(Option<T>),
+{
+    for cell in cellindexarray.iter() {
+        sink(cell.map(&f));
+    }
+}
+
+/// Apply `f` to all non-null cells and pass the results to `sink` in the order of the rows.
+///
+/// The cells are mapped in parallel in chunks of [`MAP_CHUNK_SIZE`] rows.
+#[cfg(feature = "rayon")]
+fn for_each_mapped_cell<T, F, S>(cellindexarray: &CellIndexArray, f: F, mut sink: S)
+where
+    F: Fn(CellIndex) -> T + Sync,
+    T: Send,
+    S: FnMut(Option<T>),
+{
+    use rayon::prelude::{IndexedParallelIterator, IntoParallelIterator, ParallelIterator};
+
+    let mut chunk = Vec::with_capacity(min(cellindexarray.len(), MAP_CHUNK_SIZE));
+    for chunk_start in (0..cellindexarray.len()).step_by(MAP_CHUNK_SIZE) {
+        let chunk_end = min(chunk_start + MAP_CHUNK_SIZE, cellindexarray.len());
+        (chunk_start..chunk_end)
+            .into_par_iter()
+            .map(|i| cellindexarray.get(i).map(&f))
+            .collect_into_vec(&mut chunk);
+        chunk.drain(..).for_each(&mut sink);
+    }
 }
 
 fn build_grid_disk<F, O: OffsetSizeTrait>(
@@ -139,25 +328,27 @@ where
         cellindexarray.len(),
     );
 
-    for cell in cellindexarray.iter() {
-        let is_valid = match cell {
-            Some(cell) => {
-                for (grid_cell, grid_distance) in cell.grid_disk_distances::<Vec<_>>(k).into_iter()
-                {
-                    if filter(grid_cell, grid_distance) {
-                        grid_cells_builder.values().append_value(grid_cell);
-                        grid_distancess_builder.values().append_value(grid_distance);
+    for_each_mapped_cell(
+        cellindexarray,
+        |cell| cell.grid_disk_distances::<Vec<_>>(k),
+        |disk| {
+            let is_valid = match disk {
+                Some(disk) => {
+                    for (grid_cell, grid_distance) in disk {
+                        if filter(grid_cell, grid_distance) {
+                            grid_cells_builder.values().append_value(grid_cell);
+                            grid_distancess_builder.values().append_value(grid_distance);
+                        }
                     }
+                    true
                 }
+                None => false,
+            };
 
-                true
-            }
-            None => false,
-        };
-
-        grid_cells_builder.append(is_valid);
-        grid_distancess_builder.append(is_valid)
-    }
+            grid_cells_builder.append(is_valid);
+            grid_distancess_builder.append(is_valid)
+        },
+    );
 
     let grid_cells = grid_cells_builder.finish()?;
     let grid_distances = grid_distancess_builder.finish();
@@ -169,3 +360,311 @@ where
         distances: grid_distances,
     })
 }
+
+/// Visit the grid disks of all cells in the rows accepted by `include_row` and combine the
+/// values produced by `value_fn` for the row and grid distance of each reached cell using `merge`.
+#[cfg(not(feature = "rayon"))]
+fn fold_grid_disks<T, R, V, M>(
+    cellindexarray: &CellIndexArray,
+    k: u32,
+    include_row: R,
+    value_fn: V,
+    merge: M,
+) -> HashMap<CellIndex, T>
+where
+    R: Fn(usize) -> bool,
+    V: Fn(usize, u32) -> T,
+    M: Fn(&mut T, T),
+{
+    let mut cellmap = HashMap::with_capacity(cellindexarray.len());
+    for (row_i, cell) in cellindexarray.iter().enumerate() {
+        if let Some(cell) = cell.filter(|_| include_row(row_i)) {
+            insert_grid_disk(&mut cellmap, row_i, cell, k, &value_fn, &merge);
+        }
+    }
+    cellmap
+}
+
+/// Visit the grid disks of all cells in the rows accepted by `include_row` and combine the
+/// values produced by `value_fn` for the row and grid distance of each reached cell using `merge`.
+#[cfg(feature = "rayon")]
+fn fold_grid_disks<T, R, V, M>(
+    cellindexarray: &CellIndexArray,
+    k: u32,
+    include_row: R,
+    value_fn: V,
+    merge: M,
+) -> HashMap<CellIndex, T>
+where
+    T: Send,
+    R: Fn(usize) -> bool + Sync,
+    V: Fn(usize, u32) -> T + Sync,
+    M: Fn(&mut T, T) + Sync,
+{
+    use rayon::prelude::{IntoParallelIterator, ParallelIterator};
+
+    (0..cellindexarray.len())
+        .into_par_iter()
+        .fold(HashMap::new, |mut cellmap, row_i| {
+            if let Some(cell) = cellindexarray.get(row_i).filter(|_| include_row(row_i)) {
+                insert_grid_disk(&mut cellmap, row_i, cell, k, &value_fn, &merge);
+            }
+            cellmap
+        })
+        .reduce(HashMap::new, |a, b| {
+            // merge the smaller map into the larger one
+            let (mut large, small) = if a.len() > b.len() { (a, b) } else { (b, a) };
+            for (grid_cell, value) in small {
+                insert_value(&mut large, grid_cell, value, &merge);
+            }
+            large
+        })
+}
+
+fn insert_grid_disk<T, V, M>(
+    cellmap: &mut HashMap<CellIndex, T>,
+    row_i: usize,
+    cell: CellIndex,
+    k: u32,
+    value_fn: &V,
+    merge: &M,
+) where
+    V: Fn(usize, u32) -> T,
+    M: Fn(&mut T, T),
+{
+    for (grid_cell, grid_distance) in cell.grid_disk_distances::<Vec<_>>(k).into_iter() {
+        insert_value(cellmap, grid_cell, value_fn(row_i, grid_distance), merge);
+    }
+}
+
+fn insert_value<T, M>(
+    cellmap: &mut HashMap<CellIndex, T>,
+    grid_cell: CellIndex,
+    value: T,
+    merge: &M,
+) where
+    M: Fn(&mut T, T),
+{
+    match cellmap.entry(grid_cell) {
+        Entry::Occupied(mut e) => merge(e.get_mut(), value),
+        Entry::Vacant(e) => {
+            e.insert(value);
+        }
+    };
+}
+
+/// The entries of `cellmap` sorted ascending by cell.
+fn into_sorted_vec<T: Send>(cellmap: HashMap<CellIndex, T>) -> Vec<(CellIndex, T)> {
+    let mut entries: Vec<_> = cellmap.into_iter().collect();
+
+    #[cfg(feature = "rayon")]
+    {
+        use rayon::slice::ParallelSliceMut;
+        entries.par_sort_unstable_by_key(|(cell, _)| *cell);
+    }
+
+    #[cfg(not(feature = "rayon"))]
+    entries.sort_unstable_by_key(|(cell, _)| *cell);
+
+    entries
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use arrow::array::{Array, Float64Array};
+    use h3o::{CellIndex, LatLng, Resolution};
+
+    use crate::algorithm::{
+        GridDiskSmoothed, GridOp, KAggregationMethod, SmoothingAggregation, SmoothingKernel,
+    };
+    use crate::array::{CellIndexArray, H3ListArray};
+    use crate::error::Error;
+
+    #[test]
+    fn grid_disk_layout() {
+        let cells: CellIndexArray = vec![
+            Some(LatLng::new(23.4, 12.4).unwrap().to_cell(Resolution::Nine)),
+            None,
+            Some(Resolution::Nine.pentagons().next().unwrap()),
+        ]
+        .into();
+
+        let disks: H3ListArray<CellIndex> = cells.grid_disk(2).unwrap();
+        assert_eq!(disks.len(), 3);
+        assert_eq!(disks.value_length(0), 19);
+        assert!(disks.listarray().is_null(1));
+        assert_eq!(disks.value_length(2), 16);
+        assert_eq!(
+            disks.value(0).iter().flatten().collect::<Vec<_>>(),
+            cells.get(0).unwrap().grid_disk::<Vec<_>>(2)
+        );
+
+        let rings = cells.grid_ring_distances::<i64>(1, 2).unwrap();
+        assert_eq!(rings.cells.value_length(0), 18);
+        assert!(rings.cells.listarray().is_null(1));
+        assert_eq!(rings.distances.value_length(0), 18);
+    }
+
+    #[test]
+    fn grid_disk_row_order() {
+        let cell = LatLng::new(23.4, 12.4).unwrap().to_cell(Resolution::Nine);
+        // spans multiple chunks when mapped in parallel
+        let cells: CellIndexArray = cell
+            .grid_disk::<Vec<_>>(40)
+            .into_iter()
+            .enumerate()
+            .map(|(i, cell)| (i % 3 != 1).then_some(cell))
+            .collect::<Vec<_>>()
+            .into();
+        assert!(cells.len() > 4096);
+
+        let disks: H3ListArray<CellIndex> = cells.grid_disk(1).unwrap();
+        assert_eq!(disks.len(), cells.len());
+        for (i, cell) in cells.iter().enumerate() {
+            match cell {
+                Some(cell) => assert_eq!(
+                    disks.value(i).iter().flatten().collect::<Vec<_>>(),
+                    cell.grid_disk::<Vec<_>>(1)
+                ),
+                None => assert!(disks.listarray().is_null(i)),
+            }
+        }
+    }
+
+    #[test]
+    fn aggregate_k() {
+        let cell = LatLng::new(23.4, 12.4).unwrap().to_cell(Resolution::Nine);
+        let neighbor = cell.grid_ring_fast(1).flatten().next().unwrap();
+        let cells: CellIndexArray = vec![Some(cell), None, Some(neighbor)].into();
+
+        let aggregated = cells
+            .grid_disk_aggregate_k(1, KAggregationMethod::Min)
+            .unwrap();
+        let result: Vec<_> = aggregated.cells.iter().flatten().collect();
+        let mut sorted = result.clone();
+        sorted.sort_unstable();
+        assert_eq!(result, sorted);
+
+        let pos = result.iter().position(|c| *c == neighbor).unwrap();
+        assert_eq!(aggregated.distances.value(pos), 0);
+        let aggregated = cells
+            .grid_disk_aggregate_k(1, KAggregationMethod::Max)
+            .unwrap();
+        assert_eq!(aggregated.distances.value(pos), 1);
+    }
+
+    #[test]
+    fn smooth() {
+        let cell = LatLng::new(23.4, 12.4).unwrap().to_cell(Resolution::Nine);
+        let neighbor = cell.grid_ring_fast(1).flatten().next().unwrap();
+        let cells: CellIndexArray = vec![Some(cell), None, Some(neighbor), Some(neighbor)].into();
+        let values = Float64Array::from(vec![Some(2.0), Some(100.0), Some(4.0), None]);
+
+        let value_at = |smoothed: &GridDiskSmoothed, c: CellIndex| {
+            let pos = smoothed.cells.iter().position(|x| x == Some(c)).unwrap();
+            smoothed.values.value(pos)
+        };
+
+        let smoothed = cells
+            .grid_disk_smooth(
+                &values,
+                1,
+                &SmoothingKernel::Uniform,
+                SmoothingAggregation::Sum,
+            )
+            .unwrap();
+        assert_eq!(value_at(&smoothed, cell), 6.0);
+        assert_eq!(value_at(&smoothed, neighbor), 6.0);
+
+        let smoothed = cells
+            .grid_disk_smooth(
+                &values,
+                1,
+                &SmoothingKernel::Linear,
+                SmoothingAggregation::Sum,
+            )
+            .unwrap();
+        assert_eq!(value_at(&smoothed, cell), 2.0 + 4.0 * 0.5);
+
+        let smoothed = cells
+            .grid_disk_smooth(
+                &values,
+                1,
+                &SmoothingKernel::Linear,
+                SmoothingAggregation::Mean,
+            )
+            .unwrap();
+        assert_eq!(value_at(&smoothed, cell), (2.0 + 4.0 * 0.5) / 1.5);
+
+        let kernel = SmoothingKernel::Custom(Arc::new(|d| if d == 0 { 1.0 } else { 0.0 }));
+        let smoothed = cells
+            .grid_disk_smooth(&values, 1, &kernel, SmoothingAggregation::Mean)
+            .unwrap();
+        assert_eq!(value_at(&smoothed, cell), 2.0);
+        assert_eq!(smoothed.cells.len(), 10);
+        assert_eq!(smoothed.values.null_count(), 8);
+
+        let kernel = SmoothingKernel::Gaussian { sigma: 1.0 };
+        assert_eq!(kernel.weight(0, 1), 1.0);
+        assert!(kernel.weight(1, 1) < 1.0);
+
+        assert!(cells
+            .grid_disk_smooth(
+                &values.slice(0, 1),
+                1,
+                &SmoothingKernel::Uniform,
+                SmoothingAggregation::Sum
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn smooth_invalid_gaussian_sigma() {
+        let cell = LatLng::new(23.4, 12.4).unwrap().to_cell(Resolution::Nine);
+        let cells: CellIndexArray = vec![Some(cell)].into();
+        let values = Float64Array::from(vec![Some(2.0)]);
+
+        for sigma in [0.0, -1.0, f64::NAN, f64::INFINITY] {
+            assert!(matches!(
+                cells.grid_disk_smooth(
+                    &values,
+                    1,
+                    &SmoothingKernel::Gaussian { sigma },
+                    SmoothingAggregation::Mean,
+                ),
+                Err(Error::InvalidKernelSigma(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn pairwise_distance_and_path() {
+        let origin = LatLng::new(23.4, 12.4).unwrap().to_cell(Resolution::Nine);
+        let destination = LatLng::new(23.41, 12.42).unwrap().to_cell(Resolution::Nine);
+        let far_away = LatLng::new(-40.3, 120.5).unwrap().to_cell(Resolution::Nine);
+
+        let origins: CellIndexArray = vec![Some(origin), Some(origin), None].into();
+        let destinations: CellIndexArray =
+            vec![Some(destination), Some(far_away), Some(origin)].into();
+
+        let distances = origins.grid_distance(&destinations).unwrap();
+        assert_eq!(distances.len(), 3);
+        assert_eq!(
+            distances.value(0),
+            origin.grid_distance(destination).unwrap()
+        );
+        assert!(distances.is_null(1));
+        assert!(distances.is_null(2));
+
+        let paths: H3ListArray<CellIndex> = origins.grid_path_cells(&destinations).unwrap();
+        assert_eq!(paths.len(), 3);
+        assert_eq!(paths.value_length(0) as i32, distances.value(0) + 1);
+        assert_eq!(paths.value(0).get(0), Some(origin));
+        assert!(paths.listarray().is_null(1));
+        assert!(paths.listarray().is_null(2));
+
+        assert!(origins.grid_distance(&destinations.slice(0, 1)).is_err());
+    }
+}
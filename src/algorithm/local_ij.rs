@@ -0,0 +1,161 @@
+use crate::array::{check_same_length, CellIndexArray};
+use crate::error::Error;
+use arrow::array::Int32Array;
+use h3o::{CellIndex, CoordIJ, LocalIJ};
+
+/// Local IJ coordinates of cells relative to an anchor cell.
+pub struct LocalIjArrays {
+    pub i: Int32Array,
+    pub j: Int32Array,
+}
+
+/// Conversion between cells and local IJ coordinates.
+///
+/// Values are null where the conversion fails, which happens when the cells are too far
+/// from the anchor or in the vicinity of pentagons.
+pub trait LocalIjOp
+where
+    Self: Sized,
+{
+    /// The coordinates of all cells relative to `anchor`.
+    fn to_local_ij(&self, anchor: CellIndex) -> Result<LocalIjArrays, Error>;
+
+    /// The coordinates of each cell relative to the anchor at the same position in `anchors`.
+    fn to_local_ij_with_anchors(&self, anchors: &CellIndexArray) -> Result<LocalIjArrays, Error>;
+
+    /// The cells at the coordinates relative to `anchor`.
+    fn from_local_ij(anchor: CellIndex, i: &Int32Array, j: &Int32Array) -> Result<Self, Error>;
+
+    /// The cells at the coordinates relative to the anchor at the same position in `anchors`.
+    fn from_local_ij_with_anchors(
+        anchors: &CellIndexArray,
+        i: &Int32Array,
+        j: &Int32Array,
+    ) -> Result<Self, Error>;
+}
+
+impl LocalIjOp for CellIndexArray {
+    fn to_local_ij(&self, anchor: CellIndex) -> Result<LocalIjArrays, Error> {
+        Ok(to_localijarrays(self.iter().map(|cell| {
+            cell.and_then(|cell| cell.to_local_ij(anchor).ok())
+        })))
+    }
+
+    fn to_local_ij_with_anchors(&self, anchors: &CellIndexArray) -> Result<LocalIjArrays, Error> {
+        check_same_length(self.len(), anchors.len())?;
+        Ok(to_localijarrays(self.iter().zip(anchors.iter()).map(
+            |(cell, anchor)| match (cell, anchor) {
+                (Some(cell), Some(anchor)) => cell.to_local_ij(anchor).ok(),
+                _ => None,
+            },
+        )))
+    }
+
+    fn from_local_ij(anchor: CellIndex, i: &Int32Array, j: &Int32Array) -> Result<Self, Error> {
+        check_same_length(i.len(), j.len())?;
+        Ok(i.iter()
+            .zip(j.iter())
+            .map(|(i, j)| from_coordinates(Some(anchor), i, j))
+            .collect())
+    }
+
+    fn from_local_ij_with_anchors(
+        anchors: &CellIndexArray,
+        i: &Int32Array,
+        j: &Int32Array,
+    ) -> Result<Self, Error> {
+        check_same_length(i.len(), j.len())?;
+        check_same_length(anchors.len(), i.len())?;
+        Ok(anchors
+            .iter()
+            .zip(i.iter().zip(j.iter()))
+            .map(|(anchor, (i, j))| from_coordinates(anchor, i, j))
+            .collect())
+    }
+}
+
+fn to_localijarrays<I>(local_ijs: I) -> LocalIjArrays
+where
+    I: Iterator<Item = Option<LocalIJ>>,
+{
+    let (i, j): (Vec<_>, Vec<_>) = local_ijs
+        .map(|local_ij| {
+            local_ij
+                .map(|local_ij| (local_ij.coord.i, local_ij.coord.j))
+                .unzip()
+        })
+        .unzip();
+    LocalIjArrays {
+        i: i.into(),
+        j: j.into(),
+    }
+}
+
+fn from_coordinates(
+    anchor: Option<CellIndex>,
+    i: Option<i32>,
+    j: Option<i32>,
+) -> Option<CellIndex> {
+    match (anchor, i, j) {
+        (Some(anchor), Some(i), Some(j)) => {
+            CellIndex::try_from(LocalIJ::new(anchor, CoordIJ::new(i, j))).ok()
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow::array::{Array, Int32Array};
+    use h3o::{LatLng, Resolution};
+
+    use crate::algorithm::LocalIjOp;
+    use crate::array::CellIndexArray;
+
+    #[test]
+    fn roundtrip() {
+        let anchor = LatLng::new(23.4, 12.4).unwrap().to_cell(Resolution::Nine);
+        let mut cells: Vec<_> = anchor
+            .grid_disk::<Vec<_>>(2)
+            .into_iter()
+            .map(Some)
+            .collect();
+        cells.push(None);
+        let cells: CellIndexArray = cells.into();
+
+        let local_ij = cells.to_local_ij(anchor).unwrap();
+        assert_eq!(local_ij.i.len(), cells.len());
+        assert_eq!(local_ij.i.null_count(), 1);
+
+        let cells2 = CellIndexArray::from_local_ij(anchor, &local_ij.i, &local_ij.j).unwrap();
+        assert!(cells == cells2);
+    }
+
+    #[test]
+    fn with_anchors() {
+        let anchor_a = LatLng::new(23.4, 12.4).unwrap().to_cell(Resolution::Nine);
+        let anchor_b = LatLng::new(-12.3, 0.5).unwrap().to_cell(Resolution::Nine);
+        let far_away = LatLng::new(40.3, 120.5).unwrap().to_cell(Resolution::Nine);
+
+        let cells: CellIndexArray = vec![anchor_a, anchor_b, far_away].into();
+        let anchors: CellIndexArray = vec![anchor_a, anchor_b, anchor_b].into();
+
+        let local_ij = cells.to_local_ij_with_anchors(&anchors).unwrap();
+        assert!(local_ij.i.is_valid(0));
+        assert!(local_ij.i.is_valid(1));
+        assert!(local_ij.i.is_null(2));
+
+        let cells2 =
+            CellIndexArray::from_local_ij_with_anchors(&anchors, &local_ij.i, &local_ij.j).unwrap();
+        assert_eq!(cells2.get(0), Some(anchor_a));
+        assert_eq!(cells2.get(1), Some(anchor_b));
+        assert_eq!(cells2.get(2), None);
+
+        assert!(CellIndexArray::from_local_ij(
+            anchor_a,
+            &Int32Array::from(vec![0]),
+            &Int32Array::from(vec![0, 1])
+        )
+        .is_err());
+    }
+}
@@ -1,3 +1,4 @@
+pub mod aggregate;
 pub mod bounding_rect;
 pub mod centroid;
 pub mod change_resolution;
@@ -6,8 +7,12 @@ pub mod concave_hull;
 pub mod convex_hull;
 pub mod coordinates;
 pub mod grid;
+pub mod local_ij;
+pub mod set_ops;
 pub mod string;
 
+#[allow(unused_imports)]
+pub use aggregate::*;
 #[allow(unused_imports)]
 pub use bounding_rect::*;
 #[allow(unused_imports)]
@@ -25,4 +30,8 @@ pub use coordinates::*;
 #[allow(unused_imports)]
 pub use grid::*;
 #[allow(unused_imports)]
+pub use local_ij::*;
+#[allow(unused_imports)]
+pub use set_ops::*;
+#[allow(unused_imports)]
 pub use string::*;
@@ -0,0 +1,285 @@
+use crate::algorithm::compact::CellSet;
+use crate::array::CellIndexArray;
+use crate::error::Error;
+use ahash::{HashSet, HashSetExt};
+use h3o::{CellIndex, Resolution};
+
+/// Set algebra between cell arrays which takes the H3 hierarchy into account: A cell
+/// is regarded as equal to the set of all of its descendants.
+///
+/// The inputs may contain cells of mixed resolutions. When `resolution` is `None` the
+/// result is returned compacted, otherwise it is uncompacted to `resolution`. Inputs
+/// containing cells of a finer resolution than `resolution` are rejected with an error in
+/// that case.
+///
+/// Invalid/empty values are omitted.
+pub trait CellSetOp
+where
+    Self: Sized,
+{
+    /// All cells contained in `self` or `other`.
+    fn union(&self, other: &Self, resolution: Option<Resolution>) -> Result<Self, Error>;
+
+    /// All cells contained in both `self` and `other`.
+    fn intersection(&self, other: &Self, resolution: Option<Resolution>) -> Result<Self, Error>;
+
+    /// All cells contained in `self`, but not in `other`.
+    fn difference(&self, other: &Self, resolution: Option<Resolution>) -> Result<Self, Error>;
+
+    /// All cells contained in either `self` or `other`, but not in both.
+    fn symmetric_difference(
+        &self,
+        other: &Self,
+        resolution: Option<Resolution>,
+    ) -> Result<Self, Error>;
+}
+
+impl CellSetOp for CellIndexArray {
+    fn union(&self, other: &Self, resolution: Option<Resolution>) -> Result<Self, Error> {
+        check_resolution([self, other], resolution)?;
+        build_output(self.iter().chain(other.iter()).flatten(), resolution)
+    }
+
+    fn intersection(&self, other: &Self, resolution: Option<Resolution>) -> Result<Self, Error> {
+        check_resolution([self, other], resolution)?;
+        let a = normalized(self)?;
+        let b = normalized(other)?;
+
+        // the finer of both cells is contained in the intersection
+        let cells = a
+            .iter()
+            .filter(|cell| is_covered(&b, **cell))
+            .chain(b.iter().filter(|cell| is_covered(&a, **cell)))
+            .copied();
+        build_output(cells, resolution)
+    }
+
+    fn difference(&self, other: &Self, resolution: Option<Resolution>) -> Result<Self, Error> {
+        check_resolution([self, other], resolution)?;
+        let a = normalized(self)?;
+        let b = normalized(other)?;
+        build_output(difference(&a, &b), resolution)
+    }
+
+    fn symmetric_difference(
+        &self,
+        other: &Self,
+        resolution: Option<Resolution>,
+    ) -> Result<Self, Error> {
+        check_resolution([self, other], resolution)?;
+        let a = normalized(self)?;
+        let b = normalized(other)?;
+
+        let mut cells = difference(&a, &b);
+        cells.append(&mut difference(&b, &a));
+        build_output(cells, resolution)
+    }
+}
+
+/// Fail when any of the `arrays` contains a cell finer than the output `resolution`.
+fn check_resolution<'a>(
+    arrays: impl IntoIterator<Item = &'a CellIndexArray>,
+    resolution: Option<Resolution>,
+) -> Result<(), Error> {
+    let Some(resolution) = resolution else {
+        return Ok(());
+    };
+    for array in arrays {
+        if let Some(cell) = array
+            .iter()
+            .flatten()
+            .find(|cell| cell.resolution() > resolution)
+        {
+            return Err(Error::CellFinerThanResolution {
+                cell_resolution: cell.resolution(),
+                resolution,
+            });
+        }
+    }
+    Ok(())
+}
+
+/// The cells of `array` without duplicates and without cells whose ancestors are contained.
+fn normalized(array: &CellIndexArray) -> Result<HashSet<CellIndex>, Error> {
+    let mut cellset = CellSet::default();
+    for cell in array.iter().flatten() {
+        cellset.insert(cell);
+    }
+    cellset.finalize(false)?;
+    Ok(cellset.iter_compacted().collect())
+}
+
+/// `cell` or any of its ancestors is contained in `set`.
+fn is_covered(set: &HashSet<CellIndex>, cell: CellIndex) -> bool {
+    let mut r = Some(cell.resolution());
+    while let Some(resolution) = r {
+        if let Some(parent) = cell.parent(resolution) {
+            if set.contains(&parent) {
+                return true;
+            }
+        }
+        r = resolution.pred();
+    }
+    false
+}
+
+fn difference(a: &HashSet<CellIndex>, b: &HashSet<CellIndex>) -> Vec<CellIndex> {
+    // all cells having descendants in `b`
+    let mut b_ancestors = HashSet::with_capacity(b.len());
+    for cell in b.iter() {
+        let mut r = cell.resolution().pred();
+        while let Some(resolution) = r {
+            if let Some(parent) = cell.parent(resolution) {
+                if !b_ancestors.insert(parent) {
+                    // all further ancestors have already been added
+                    break;
+                }
+            }
+            r = resolution.pred();
+        }
+    }
+
+    let mut out = Vec::with_capacity(a.len());
+    for cell in a.iter() {
+        subtract(*cell, b, &b_ancestors, &mut out);
+    }
+    out
+}
+
+fn subtract(
+    cell: CellIndex,
+    b: &HashSet<CellIndex>,
+    b_ancestors: &HashSet<CellIndex>,
+    out: &mut Vec<CellIndex>,
+) {
+    if is_covered(b, cell) {
+        return;
+    }
+    if !b_ancestors.contains(&cell) {
+        out.push(cell);
+        return;
+    }
+
+    // descend to the children to cut out the descendants contained in `b`.
+    if let Some(child_resolution) = cell.resolution().succ() {
+        for child in cell.children(child_resolution) {
+            subtract(child, b, b_ancestors, out);
+        }
+    }
+}
+
+fn build_output<I>(cells: I, resolution: Option<Resolution>) -> Result<CellIndexArray, Error>
+where
+    I: IntoIterator<Item = CellIndex>,
+{
+    let mut cellset = CellSet::default();
+    for cell in cells {
+        cellset.insert(cell);
+    }
+    cellset.finalize(true)?;
+
+    Ok(match resolution {
+        Some(resolution) => cellset.iter_uncompacted(resolution).collect(),
+        None => cellset.iter_compacted().collect(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::algorithm::CellSetOp;
+    use crate::array::CellIndexArray;
+    use crate::error::Error;
+    use h3o::{CellIndex, LatLng, Resolution};
+
+    fn parent_cell() -> CellIndex {
+        LatLng::new(23.4, 12.4).unwrap().to_cell(Resolution::Five)
+    }
+
+    fn some_descendants() -> Vec<CellIndex> {
+        parent_cell().children(Resolution::Seven).take(3).collect()
+    }
+
+    #[test]
+    fn intersection_with_descendants() {
+        let a: CellIndexArray = vec![parent_cell()].into();
+        let b: CellIndexArray = some_descendants().into();
+
+        let mut cells: Vec<_> = a.intersection(&b, None).unwrap().iter().flatten().collect();
+        cells.sort_unstable();
+        assert_eq!(cells, some_descendants());
+
+        let other = LatLng::new(12.3, 0.5).unwrap().to_cell(Resolution::Seven);
+        let c: CellIndexArray = vec![other].into();
+        assert!(a.intersection(&c, None).unwrap().is_empty());
+    }
+
+    #[test]
+    fn union_compacts() {
+        let a: CellIndexArray = parent_cell()
+            .children(Resolution::Six)
+            .take(3)
+            .collect::<Vec<_>>()
+            .into();
+        let b: CellIndexArray = parent_cell()
+            .children(Resolution::Six)
+            .skip(3)
+            .collect::<Vec<_>>()
+            .into();
+
+        let union = a.union(&b, None).unwrap();
+        assert_eq!(
+            union.iter().flatten().collect::<Vec<_>>(),
+            vec![parent_cell()]
+        );
+
+        let union = a.union(&b, Some(Resolution::Six)).unwrap();
+        assert_eq!(union.len(), 7);
+    }
+
+    #[test]
+    fn difference_cuts_out_descendants() {
+        let a: CellIndexArray = vec![parent_cell()].into();
+        let b: CellIndexArray = some_descendants().into();
+
+        let difference = a.difference(&b, Some(Resolution::Seven)).unwrap();
+        assert_eq!(difference.len(), 49 - 3);
+        assert!(difference
+            .iter()
+            .flatten()
+            .all(|cell| !some_descendants().contains(&cell)));
+
+        assert!(b.difference(&a, None).unwrap().is_empty());
+    }
+
+    #[test]
+    fn finer_cells_than_resolution_fail() {
+        let a: CellIndexArray = some_descendants().into();
+        let b: CellIndexArray = vec![parent_cell()].into();
+
+        assert!(matches!(
+            a.union(&b, Some(Resolution::Five)),
+            Err(Error::CellFinerThanResolution {
+                cell_resolution: Resolution::Seven,
+                resolution: Resolution::Five
+            })
+        ));
+        assert!(b.difference(&a, Some(Resolution::Six)).is_err());
+        assert!(a.intersection(&b, Some(Resolution::Seven)).is_ok());
+    }
+
+    #[test]
+    fn symmetric_difference() {
+        let a: CellIndexArray = vec![parent_cell()].into();
+        let other = LatLng::new(12.3, 0.5).unwrap().to_cell(Resolution::Seven);
+        let mut b_cells = some_descendants();
+        b_cells.push(other);
+        let b: CellIndexArray = b_cells.into();
+
+        let symmetric_difference = a.symmetric_difference(&b, Some(Resolution::Seven)).unwrap();
+        assert_eq!(symmetric_difference.len(), 49 - 3 + 1);
+        assert!(symmetric_difference
+            .iter()
+            .flatten()
+            .any(|cell| cell == other));
+    }
+}
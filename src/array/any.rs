@@ -0,0 +1,226 @@
+use std::fmt;
+
+use arrow::array::{GenericStringArray, OffsetSizeTrait, UInt64Array};
+use geo::BoundingRect;
+use geo_types::Rect;
+use h3o::IndexMode;
+
+use crate::algorithm::ToGenericStringArray;
+use crate::array::{CellIndexArray, DirectedEdgeIndexArray, VertexIndexArray};
+use crate::error::Error;
+
+/// Number of values per H3 index mode.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct IndexModeCounts {
+    pub cells: usize,
+    pub directed_edges: usize,
+    pub vertexes: usize,
+
+    /// values with any other index mode
+    pub other: usize,
+}
+
+impl fmt::Display for IndexModeCounts {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "cells: {}, directed edges: {}, vertexes: {}, other: {}",
+            self.cells, self.directed_edges, self.vertexes, self.other
+        )
+    }
+}
+
+/// Bit offset of the 4 bit index mode within an H3 index.
+pub(crate) const MODE_OFFSET: usize = 59;
+pub(crate) const MODE_MASK: u64 = 0b1111 << MODE_OFFSET;
+
+/// Count the H3 index modes of the non-null values by inspecting their mode bits.
+///
+/// The values are not validated.
+pub fn count_index_modes(array: &UInt64Array) -> IndexModeCounts {
+    let mut counts = IndexModeCounts::default();
+    for value in array.iter().flatten() {
+        let mode = ((value & MODE_MASK) >> MODE_OFFSET) as u8;
+        if mode == u8::from(IndexMode::Cell) {
+            counts.cells += 1;
+        } else if mode == u8::from(IndexMode::DirectedEdge) {
+            counts.directed_edges += 1;
+        } else if mode == u8::from(IndexMode::Vertex) {
+            counts.vertexes += 1;
+        } else {
+            counts.other += 1;
+        }
+    }
+    counts
+}
+
+/// Detect the H3 index mode shared by all non-null values of `array`.
+///
+/// Arrays without any non-null values are detected as cells. Arrays mixing multiple
+/// modes, or containing modes other than cells, directed edges and vertexes, result
+/// in [`Error::AmbiguousIndexMode`].
+pub fn detect_index_mode(array: &UInt64Array) -> Result<IndexMode, Error> {
+    let counts = count_index_modes(array);
+    match counts {
+        IndexModeCounts {
+            directed_edges: 0,
+            vertexes: 0,
+            other: 0,
+            ..
+        } => Ok(IndexMode::Cell),
+        IndexModeCounts {
+            cells: 0,
+            vertexes: 0,
+            other: 0,
+            ..
+        } => Ok(IndexMode::DirectedEdge),
+        IndexModeCounts {
+            cells: 0,
+            directed_edges: 0,
+            other: 0,
+            ..
+        } => Ok(IndexMode::Vertex),
+        _ => Err(Error::AmbiguousIndexMode(counts)),
+    }
+}
+
+/// Type-erased H3 array for columns whose index mode is only known at runtime.
+#[derive(Clone, PartialEq)]
+pub enum AnyH3Array {
+    Cell(CellIndexArray),
+    DirectedEdge(DirectedEdgeIndexArray),
+    Vertex(VertexIndexArray),
+}
+
+macro_rules! dispatch {
+    ($any:expr, $array:ident => $expr:expr) => {
+        match $any {
+            AnyH3Array::Cell($array) => $expr,
+            AnyH3Array::DirectedEdge($array) => $expr,
+            AnyH3Array::Vertex($array) => $expr,
+        }
+    };
+}
+
+impl AnyH3Array {
+    pub fn index_mode(&self) -> IndexMode {
+        match self {
+            Self::Cell(_) => IndexMode::Cell,
+            Self::DirectedEdge(_) => IndexMode::DirectedEdge,
+            Self::Vertex(_) => IndexMode::Vertex,
+        }
+    }
+
+    pub fn primitive_array(&self) -> &UInt64Array {
+        dispatch!(self, array => array.primitive_array())
+    }
+
+    pub fn len(&self) -> usize {
+        self.primitive_array().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.primitive_array().is_empty()
+    }
+
+    pub fn slice(&self, offset: usize, length: usize) -> Self {
+        dispatch!(self, array => array.slice(offset, length).into())
+    }
+}
+
+impl TryFrom<UInt64Array> for AnyH3Array {
+    type Error = Error;
+
+    /// Detects the index mode using [`detect_index_mode`] and validates the values.
+    fn try_from(value: UInt64Array) -> Result<Self, Self::Error> {
+        Ok(match detect_index_mode(&value)? {
+            IndexMode::Cell => Self::Cell(value.try_into()?),
+            IndexMode::DirectedEdge => Self::DirectedEdge(value.try_into()?),
+            IndexMode::Vertex => Self::Vertex(value.try_into()?),
+            _ => return Err(Error::AmbiguousIndexMode(count_index_modes(&value))),
+        })
+    }
+}
+
+impl From<CellIndexArray> for AnyH3Array {
+    fn from(value: CellIndexArray) -> Self {
+        Self::Cell(value)
+    }
+}
+
+impl From<DirectedEdgeIndexArray> for AnyH3Array {
+    fn from(value: DirectedEdgeIndexArray) -> Self {
+        Self::DirectedEdge(value)
+    }
+}
+
+impl From<VertexIndexArray> for AnyH3Array {
+    fn from(value: VertexIndexArray) -> Self {
+        Self::Vertex(value)
+    }
+}
+
+impl From<AnyH3Array> for UInt64Array {
+    fn from(value: AnyH3Array) -> Self {
+        dispatch!(value, array => array.into())
+    }
+}
+
+impl<O: OffsetSizeTrait> ToGenericStringArray<O> for AnyH3Array {
+    fn to_genericstringarray(&self) -> Result<GenericStringArray<O>, Error> {
+        dispatch!(self, array => array.to_genericstringarray())
+    }
+}
+
+impl BoundingRect<f64> for AnyH3Array {
+    type Output = Option<Rect>;
+
+    fn bounding_rect(&self) -> Self::Output {
+        dispatch!(self, array => array.bounding_rect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow::array::{GenericStringArray, UInt64Array};
+    use h3o::{IndexMode, LatLng, Resolution};
+
+    use crate::algorithm::ToGenericStringArray;
+    use crate::array::{detect_index_mode, AnyH3Array, IndexModeCounts};
+    use crate::error::Error;
+
+    #[test]
+    fn detect_modes() {
+        let cell = LatLng::new(23.4, 12.4).unwrap().to_cell(Resolution::Five);
+        let edge = cell.edges().next().unwrap();
+        let vertex = cell.vertexes().next().unwrap();
+
+        let any =
+            AnyH3Array::try_from(UInt64Array::from(vec![Some(u64::from(cell)), None])).unwrap();
+        assert_eq!(any.index_mode(), IndexMode::Cell);
+        let strings: GenericStringArray<i32> = any.to_genericstringarray().unwrap();
+        assert_eq!(strings.value(0), cell.to_string());
+
+        let any = AnyH3Array::try_from(UInt64Array::from(vec![u64::from(edge)])).unwrap();
+        assert_eq!(any.index_mode(), IndexMode::DirectedEdge);
+
+        let any = AnyH3Array::try_from(UInt64Array::from(vec![u64::from(vertex)])).unwrap();
+        assert_eq!(any.index_mode(), IndexMode::Vertex);
+
+        let res = detect_index_mode(&UInt64Array::from(vec![
+            u64::from(cell),
+            u64::from(edge),
+            u64::from(edge),
+            0,
+        ]));
+        assert!(matches!(
+            res,
+            Err(Error::AmbiguousIndexMode(IndexModeCounts {
+                cells: 1,
+                directed_edges: 2,
+                vertexes: 0,
+                other: 1
+            }))
+        ));
+    }
+}
@@ -0,0 +1,138 @@
+use arrow::array::{Array, FixedSizeBinaryArray, UInt64Array};
+use arrow::buffer::Buffer;
+
+use crate::array::{H3Array, H3IndexArrayValue};
+use crate::error::Error;
+
+const H3INDEX_BYTES: usize = 8;
+
+/// Byte order of H3 indexes encoded as 8-byte binary values.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ByteOrder {
+    /// The byte order of the values matches the numeric order of the indexes, which makes
+    /// the values usable as sortable keys.
+    #[default]
+    BigEndian,
+    LittleEndian,
+}
+
+impl ByteOrder {
+    fn encode(self, h3index: u64) -> [u8; H3INDEX_BYTES] {
+        match self {
+            Self::BigEndian => h3index.to_be_bytes(),
+            Self::LittleEndian => h3index.to_le_bytes(),
+        }
+    }
+
+    fn decode(self, bytes: [u8; H3INDEX_BYTES]) -> u64 {
+        match self {
+            Self::BigEndian => u64::from_be_bytes(bytes),
+            Self::LittleEndian => u64::from_le_bytes(bytes),
+        }
+    }
+}
+
+impl<IX> H3Array<IX>
+where
+    IX: H3IndexArrayValue,
+{
+    /// Encode the indexes as `FixedSizeBinary(8)` values using the given `byte_order`.
+    pub fn to_fixedsizebinaryarray(&self, byte_order: ByteOrder) -> FixedSizeBinaryArray {
+        let bytes: Vec<u8> = self
+            .primitive_array
+            .values()
+            .iter()
+            .flat_map(|h3index| byte_order.encode(*h3index))
+            .collect();
+
+        FixedSizeBinaryArray::new(
+            H3INDEX_BYTES as i32,
+            Buffer::from_vec(bytes),
+            self.primitive_array.nulls().cloned(),
+        )
+    }
+}
+
+impl<IX> H3Array<IX>
+where
+    IX: H3IndexArrayValue,
+    H3Array<IX>: TryFrom<UInt64Array, Error = Error>,
+{
+    /// Build a validated array from `FixedSizeBinary(8)` values using the given `byte_order`.
+    ///
+    /// Arrays of any other value size result in [`Error::UnsupportedDataType`].
+    pub fn try_from_fixedsizebinaryarray(
+        array: &FixedSizeBinaryArray,
+        byte_order: ByteOrder,
+    ) -> Result<Self, Error> {
+        if array.value_length() as usize != H3INDEX_BYTES {
+            return Err(Error::UnsupportedDataType(array.data_type().clone()));
+        }
+
+        array
+            .iter()
+            .map(|value| value.map(|bytes| byte_order.decode(bytes.try_into().expect("8 bytes"))))
+            .collect::<UInt64Array>()
+            .try_into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow::array::{Array, FixedSizeBinaryArray};
+    use h3o::{LatLng, Resolution};
+
+    use crate::array::{ByteOrder, CellIndexArray};
+    use crate::error::Error;
+
+    #[test]
+    fn roundtrip() {
+        let cell_a = LatLng::new(23.4, 12.4).unwrap().to_cell(Resolution::Five);
+        let cell_b = LatLng::new(12.3, 0.5).unwrap().to_cell(Resolution::Nine);
+        let cells: CellIndexArray = vec![Some(cell_a), None, Some(cell_b)].into();
+
+        for byte_order in [ByteOrder::BigEndian, ByteOrder::LittleEndian] {
+            let binary = cells.to_fixedsizebinaryarray(byte_order);
+            assert_eq!(binary.len(), 3);
+            assert!(binary.is_null(1));
+
+            let decoded =
+                CellIndexArray::try_from_fixedsizebinaryarray(&binary, byte_order).unwrap();
+            assert_eq!(
+                decoded.iter().collect::<Vec<_>>(),
+                vec![Some(cell_a), None, Some(cell_b)]
+            );
+        }
+
+        let binary = cells.to_fixedsizebinaryarray(ByteOrder::BigEndian);
+        assert_eq!(binary.value(0), u64::from(cell_a).to_be_bytes());
+    }
+
+    #[test]
+    fn big_endian_sort_order() {
+        let cell = LatLng::new(23.4, 12.4).unwrap().to_cell(Resolution::Five);
+        let mut cells: Vec<_> = cell.grid_disk(2);
+        cells.sort_unstable();
+        let cells: CellIndexArray = cells.into();
+
+        let binary = cells.to_fixedsizebinaryarray(ByteOrder::BigEndian);
+        let values: Vec<_> = binary.iter().flatten().collect();
+        let mut sorted_values = values.clone();
+        sorted_values.sort_unstable();
+        assert_eq!(values, sorted_values);
+    }
+
+    #[test]
+    fn invalid_values() {
+        let binary = FixedSizeBinaryArray::try_from_iter(vec![[0u8; 8]].into_iter()).unwrap();
+        assert!(
+            CellIndexArray::try_from_fixedsizebinaryarray(&binary, ByteOrder::BigEndian).is_err()
+        );
+
+        let binary = FixedSizeBinaryArray::try_from_iter(vec![[0u8; 4]].into_iter()).unwrap();
+        assert!(matches!(
+            CellIndexArray::try_from_fixedsizebinaryarray(&binary, ByteOrder::BigEndian),
+            Err(Error::UnsupportedDataType(_))
+        ));
+    }
+}
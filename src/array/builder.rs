@@ -0,0 +1,99 @@
+use std::marker::PhantomData;
+
+use arrow::array::{ArrayBuilder, UInt64Builder};
+
+use crate::array::{H3Array, H3IndexArrayValue};
+
+/// Builder for [`H3Array`]s supporting null values.
+pub struct H3ArrayBuilder<IX> {
+    array_builder: UInt64Builder,
+    h3index_phantom: PhantomData<IX>,
+}
+
+impl<IX> H3ArrayBuilder<IX>
+where
+    IX: H3IndexArrayValue,
+{
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            array_builder: UInt64Builder::with_capacity(capacity),
+            h3index_phantom: PhantomData::<IX>,
+        }
+    }
+
+    #[inline]
+    pub fn append_value(&mut self, value: IX) {
+        self.array_builder.append_value(value.into())
+    }
+
+    #[inline]
+    pub fn append_null(&mut self) {
+        self.array_builder.append_null()
+    }
+
+    #[inline]
+    pub fn append_option(&mut self, value: Option<IX>) {
+        self.array_builder.append_option(value.map(Into::into))
+    }
+
+    pub fn append_slice(&mut self, values: &[IX]) {
+        self.append_many(values.iter().cloned())
+    }
+
+    pub fn append_many<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = IX>,
+    {
+        iter.into_iter().for_each(|value| self.append_value(value))
+    }
+
+    pub fn len(&self) -> usize {
+        self.array_builder.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.array_builder.is_empty()
+    }
+
+    /// Build the array and reset this builder.
+    pub fn finish(&mut self) -> H3Array<IX> {
+        // all appended values are of the index type, so there is no need to validate
+        H3Array::from_primitive_array_unvalidated(self.array_builder.finish())
+    }
+}
+
+impl<IX> Default for H3ArrayBuilder<IX>
+where
+    IX: H3IndexArrayValue,
+{
+    fn default() -> Self {
+        Self::with_capacity(10)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use h3o::{CellIndex, LatLng, Resolution};
+
+    use crate::array::H3ArrayBuilder;
+
+    #[test]
+    fn build() {
+        let cell = LatLng::new(23.4, 12.4).unwrap().to_cell(Resolution::Five);
+
+        let mut builder = H3ArrayBuilder::<CellIndex>::with_capacity(5);
+        builder.append_value(cell);
+        builder.append_null();
+        builder.append_option(Some(cell));
+        builder.append_option(None);
+        builder.append_slice(&[cell]);
+        assert_eq!(builder.len(), 5);
+
+        let cells = builder.finish();
+        assert!(builder.is_empty());
+        assert_eq!(
+            cells.iter().collect::<Vec<_>>(),
+            vec![Some(cell), None, Some(cell), None, Some(cell)]
+        );
+    }
+}
@@ -1,7 +1,11 @@
-use arrow::array::{Float64Array, UInt64Array};
-use h3o::{CellIndex, Resolution};
+use arrow::array::{
+    BooleanArray, Float64Array, ListArray, ListBuilder, UInt64Array, UInt8Array, UInt8Builder,
+};
+use h3o::{CellIndex, DirectedEdgeIndex, Resolution, VertexIndex};
 
-use crate::array::{CellIndexArray, H3ListArray, H3ListArrayBuilder, ResolutionArray};
+use crate::array::{
+    check_same_length, CellIndexArray, H3ListArray, H3ListArrayBuilder, ResolutionArray,
+};
 use crate::error::Error;
 
 impl CellIndexArray {
@@ -54,10 +58,76 @@ impl CellIndexArray {
             .map(|cell| cell.map(|cell| cell.children_count(resolution)))
             .collect()
     }
+
+    /// The vertexes of each cell. Pentagons have five vertexes.
+    pub fn vertexes(&self) -> H3ListArray<VertexIndex> {
+        self.iter()
+            .map(|cell| cell.map(|cell| cell.vertexes().collect::<Vec<_>>()))
+            .collect()
+    }
+
+    /// The directed edges originating from each cell. Pentagons have five edges.
+    pub fn edges(&self) -> H3ListArray<DirectedEdgeIndex> {
+        self.iter()
+            .map(|cell| cell.map(|cell| cell.edges().collect::<Vec<_>>()))
+            .collect()
+    }
+
+    /// true where the cell is a neighbor of the cell at the same position in `other`.
+    ///
+    /// The value is null when the cells are of different resolutions.
+    pub fn is_neighbor_with(&self, other: &Self) -> Result<BooleanArray, Error> {
+        check_same_length(self.len(), other.len())?;
+        Ok(self
+            .iter()
+            .zip(other.iter())
+            .map(|(cell, other_cell)| match (cell, other_cell) {
+                (Some(cell), Some(other_cell)) => cell.is_neighbor_with(other_cell).ok(),
+                _ => None,
+            })
+            .collect())
+    }
+
+    pub fn is_pentagon(&self) -> BooleanArray {
+        self.iter()
+            .map(|cell| cell.map(|cell| cell.is_pentagon()))
+            .collect()
+    }
+
+    /// true for cells of a Class III resolution.
+    pub fn is_class_iii(&self) -> BooleanArray {
+        self.iter()
+            .map(|cell| cell.map(|cell| cell.resolution().is_class3()))
+            .collect()
+    }
+
+    pub fn base_cell(&self) -> UInt8Array {
+        self.iter()
+            .map(|cell| cell.map(|cell| u8::from(cell.base_cell())))
+            .collect()
+    }
+
+    /// The numbers of the icosahedron faces intersected by each cell.
+    pub fn icosahedron_faces(&self) -> ListArray {
+        let mut builder = ListBuilder::with_capacity(UInt8Builder::new(), self.len());
+        for value in self.iter() {
+            if let Some(cell) = value {
+                for face in cell.icosahedron_faces().iter() {
+                    builder.values().append_value(u8::from(face));
+                }
+                builder.append(true);
+            } else {
+                builder.append(false);
+            }
+        }
+        builder.finish()
+    }
 }
 
 #[cfg(test)]
 mod test {
+    use arrow::array::{Array, AsArray};
+    use arrow::datatypes::UInt8Type;
     use h3o::{LatLng, Resolution};
 
     use crate::array::CellIndexArray;
@@ -107,4 +177,86 @@ mod test {
             assert_eq!(arr.iter().next().flatten(), child.parent(Resolution::Five));
         }
     }
+
+    #[test]
+    fn cell_properties() {
+        let pentagon = Resolution::Five.pentagons().next().unwrap();
+        let hexagon = LatLng::new(12.3, 0.5).unwrap().to_cell(Resolution::Four);
+        let arr: CellIndexArray = vec![Some(pentagon), None, Some(hexagon)].into();
+
+        let is_pentagon = arr.is_pentagon();
+        assert!(is_pentagon.value(0));
+        assert!(is_pentagon.is_null(1));
+        assert!(!is_pentagon.value(2));
+
+        let is_class_iii = arr.is_class_iii();
+        assert!(is_class_iii.value(0));
+        assert!(!is_class_iii.value(2));
+
+        let base_cell = arr.base_cell();
+        assert_eq!(base_cell.value(0), u8::from(pentagon.base_cell()));
+        assert!(base_cell.is_null(1));
+        assert_eq!(base_cell.value(2), u8::from(hexagon.base_cell()));
+
+        let faces = arr.icosahedron_faces();
+        assert_eq!(faces.len(), 3);
+        assert!(faces.is_null(1));
+        // pentagons intersect five faces
+        assert_eq!(faces.value_length(0), 5);
+        assert_eq!(
+            faces.value(2).as_primitive::<UInt8Type>().values().to_vec(),
+            hexagon
+                .icosahedron_faces()
+                .iter()
+                .map(u8::from)
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn vertexes_and_edges() {
+        let pentagon = Resolution::Five.pentagons().next().unwrap();
+        let hexagon = LatLng::new(12.3, 0.5).unwrap().to_cell(Resolution::Four);
+        let arr: CellIndexArray = vec![Some(pentagon), None, Some(hexagon)].into();
+
+        let vertexes = arr.vertexes();
+        assert_eq!(vertexes.len(), 3);
+        assert_eq!(vertexes.value_length(0), 5);
+        assert!(vertexes.listarray().is_null(1));
+        assert_eq!(vertexes.value_length(2), 6);
+        assert!(vertexes
+            .value(2)
+            .iter()
+            .flatten()
+            .all(|vertex| hexagon.vertexes().any(|v| v == vertex)));
+
+        let edges = arr.edges();
+        assert_eq!(edges.value_length(0), 5);
+        assert!(edges.listarray().is_null(1));
+        assert_eq!(edges.value_length(2), 6);
+        assert!(edges
+            .value(2)
+            .origin()
+            .iter()
+            .all(|origin| origin == Some(hexagon)));
+    }
+
+    #[test]
+    fn is_neighbor_with() {
+        let cell = LatLng::new(23.4, 12.4).unwrap().to_cell(Resolution::Five);
+        let neighbor = cell.grid_ring_fast(1).flatten().next().unwrap();
+        let child = cell.center_child(Resolution::Six).unwrap();
+
+        let arr: CellIndexArray = vec![Some(cell), Some(cell), Some(cell), None].into();
+        let other: CellIndexArray =
+            vec![Some(neighbor), Some(cell), Some(child), Some(cell)].into();
+
+        let is_neighbor = arr.is_neighbor_with(&other).unwrap();
+        assert!(is_neighbor.value(0));
+        assert!(!is_neighbor.value(1));
+        assert!(is_neighbor.is_null(2));
+        assert!(is_neighbor.is_null(3));
+
+        assert!(arr.is_neighbor_with(&other.slice(0, 1)).is_err());
+    }
 }
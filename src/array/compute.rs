@@ -0,0 +1,176 @@
+//! Wrappers around the arrow compute kernels which preserve the H3 index type.
+//!
+//! All values of the inputs are already validated, so the results are not re-validated.
+
+use std::marker::PhantomData;
+
+use arrow::array::{Array, AsArray, BooleanArray, OffsetSizeTrait};
+use arrow::compute;
+use arrow::datatypes::UInt64Type;
+
+use crate::array::{H3Array, H3IndexArrayValue, H3ListArray};
+use crate::error::Error;
+
+impl<IX> H3Array<IX>
+where
+    IX: H3IndexArrayValue,
+{
+    fn from_arrayref_unvalidated(array: &dyn Array) -> Self {
+        Self::from_primitive_array_unvalidated(array.as_primitive::<UInt64Type>().clone())
+    }
+
+    /// Keep only the values where `mask` is true. Null values in `mask` are treated as false.
+    ///
+    /// The masks returned by the `SpatialIndex` of the `spatial_index` feature can be used
+    /// directly.
+    pub fn filter(&self, mask: &BooleanArray) -> Result<Self, Error> {
+        Ok(Self::from_arrayref_unvalidated(
+            compute::filter(self.primitive_array(), mask)?.as_ref(),
+        ))
+    }
+
+    /// Take the values at the positions given by the integer array `indices`.
+    pub fn take(&self, indices: &dyn Array) -> Result<Self, Error> {
+        Ok(Self::from_arrayref_unvalidated(
+            compute::take(self.primitive_array(), indices, None)?.as_ref(),
+        ))
+    }
+
+    /// Concatenate multiple arrays into a single one.
+    pub fn concat(arrays: &[&Self]) -> Result<Self, Error> {
+        let arrays: Vec<_> = arrays
+            .iter()
+            .map(|array| array.primitive_array() as &dyn Array)
+            .collect();
+        Ok(Self::from_arrayref_unvalidated(
+            compute::concat(&arrays)?.as_ref(),
+        ))
+    }
+
+    /// Interleave the values of multiple arrays. `indices` are pairs of the position of the
+    /// array in `arrays` and the position of the value within that array.
+    pub fn interleave(arrays: &[&Self], indices: &[(usize, usize)]) -> Result<Self, Error> {
+        let arrays: Vec<_> = arrays
+            .iter()
+            .map(|array| array.primitive_array() as &dyn Array)
+            .collect();
+        Ok(Self::from_arrayref_unvalidated(
+            compute::interleave(&arrays, indices)?.as_ref(),
+        ))
+    }
+}
+
+impl<IX, O> H3ListArray<IX, O>
+where
+    IX: H3IndexArrayValue,
+    O: OffsetSizeTrait,
+{
+    fn from_arrayref_unvalidated(array: &dyn Array) -> Self {
+        Self {
+            list_array: array.as_list::<O>().clone(),
+            h3index_phantom: PhantomData::<IX>,
+        }
+    }
+
+    /// Keep only the lists where `mask` is true. Null values in `mask` are treated as false.
+    pub fn filter(&self, mask: &BooleanArray) -> Result<Self, Error> {
+        Ok(Self::from_arrayref_unvalidated(
+            compute::filter(&self.list_array, mask)?.as_ref(),
+        ))
+    }
+
+    /// Take the lists at the positions given by the integer array `indices`.
+    pub fn take(&self, indices: &dyn Array) -> Result<Self, Error> {
+        Ok(Self::from_arrayref_unvalidated(
+            compute::take(&self.list_array, indices, None)?.as_ref(),
+        ))
+    }
+
+    /// Concatenate multiple list arrays into a single one.
+    pub fn concat(arrays: &[&Self]) -> Result<Self, Error> {
+        let arrays: Vec<_> = arrays
+            .iter()
+            .map(|array| &array.list_array as &dyn Array)
+            .collect();
+        Ok(Self::from_arrayref_unvalidated(
+            compute::concat(&arrays)?.as_ref(),
+        ))
+    }
+
+    /// Interleave the lists of multiple list arrays. `indices` are pairs of the position of the
+    /// array in `arrays` and the position of the list within that array.
+    pub fn interleave(arrays: &[&Self], indices: &[(usize, usize)]) -> Result<Self, Error> {
+        let arrays: Vec<_> = arrays
+            .iter()
+            .map(|array| &array.list_array as &dyn Array)
+            .collect();
+        Ok(Self::from_arrayref_unvalidated(
+            compute::interleave(&arrays, indices)?.as_ref(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow::array::{BooleanArray, UInt32Array};
+    use h3o::{CellIndex, LatLng, Resolution};
+
+    use crate::algorithm::GridOp;
+    use crate::array::{CellIndexArray, H3ListArray};
+
+    fn some_cells() -> CellIndexArray {
+        vec![
+            Some(LatLng::new(23.4, 12.4).unwrap().to_cell(Resolution::Five)),
+            None,
+            Some(LatLng::new(12.3, 0.5).unwrap().to_cell(Resolution::Nine)),
+        ]
+        .into()
+    }
+
+    #[test]
+    fn filter_and_take() {
+        let cells = some_cells();
+
+        let filtered = cells
+            .filter(&BooleanArray::from(vec![Some(false), None, Some(true)]))
+            .unwrap();
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered.get(0), cells.get(2));
+
+        let taken = cells.take(&UInt32Array::from(vec![2, 0, 1])).unwrap();
+        assert_eq!(taken.len(), 3);
+        assert_eq!(taken.get(0), cells.get(2));
+        assert_eq!(taken.get(1), cells.get(0));
+        assert_eq!(taken.get(2), None);
+    }
+
+    #[test]
+    fn concat_and_interleave() {
+        let cells = some_cells();
+        let sliced = cells.slice(2, 1);
+
+        let concatenated = CellIndexArray::concat(&[&cells, &sliced]).unwrap();
+        assert_eq!(concatenated.len(), 4);
+        assert_eq!(concatenated.get(3), cells.get(2));
+
+        let interleaved =
+            CellIndexArray::interleave(&[&cells, &sliced], &[(1, 0), (0, 0)]).unwrap();
+        assert_eq!(interleaved.len(), 2);
+        assert_eq!(interleaved.get(0), cells.get(2));
+        assert_eq!(interleaved.get(1), cells.get(0));
+    }
+
+    #[test]
+    fn list_filter_and_concat() {
+        let disks: H3ListArray<CellIndex> = some_cells().grid_disk(1).unwrap();
+
+        let filtered = disks
+            .filter(&BooleanArray::from(vec![false, false, true]))
+            .unwrap();
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered.listarray().value(0).len(), 7);
+
+        let concatenated = H3ListArray::concat(&[&disks, &filtered]).unwrap();
+        assert_eq!(concatenated.len(), 4);
+    }
+}
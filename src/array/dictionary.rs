@@ -0,0 +1,202 @@
+use std::marker::PhantomData;
+
+use arrow::array::{
+    Array, AsArray, DictionaryArray, PrimitiveArray, PrimitiveDictionaryBuilder, UInt64Array,
+};
+use arrow::datatypes::{ArrowDictionaryKeyType, ArrowNativeType, DataType, Int32Type, UInt64Type};
+
+use crate::array::{H3Array, H3IndexArrayValue};
+use crate::error::Error;
+
+/// Dictionary-encoded H3 indexes. Each distinct index is only stored once in the
+/// `UInt64` values of the dictionary.
+pub struct H3DictionaryArray<IX, K: ArrowDictionaryKeyType = Int32Type> {
+    dictionary_array: DictionaryArray<K>,
+    h3index_phantom: PhantomData<IX>,
+}
+
+impl<IX, K> H3DictionaryArray<IX, K>
+where
+    IX: H3IndexArrayValue,
+    K: ArrowDictionaryKeyType,
+{
+    pub fn dictionaryarray(&self) -> &DictionaryArray<K> {
+        &self.dictionary_array
+    }
+
+    pub fn len(&self) -> usize {
+        self.dictionary_array.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.dictionary_array.is_empty()
+    }
+
+    /// The keys referencing the `values`.
+    pub fn keys(&self) -> &PrimitiveArray<K> {
+        self.dictionary_array.keys()
+    }
+
+    /// The distinct values of the dictionary.
+    pub fn values(&self) -> H3Array<IX> {
+        H3Array::from_primitive_array_unvalidated(
+            self.dictionary_array
+                .values()
+                .as_primitive::<UInt64Type>()
+                .clone(),
+        )
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = Option<IX>> + '_ {
+        let values = self.values();
+        self.keys()
+            .iter()
+            .map(move |key| key.and_then(|key| values.get(key.as_usize())))
+    }
+
+    /// Decode into a plain array.
+    pub fn to_h3array(&self) -> Result<H3Array<IX>, Error> {
+        let array =
+            arrow::compute::take(self.dictionary_array.values().as_ref(), self.keys(), None)?;
+        Ok(H3Array::from_primitive_array_unvalidated(
+            array.as_primitive::<UInt64Type>().clone(),
+        ))
+    }
+}
+
+impl<IX, K> TryFrom<DictionaryArray<K>> for H3DictionaryArray<IX, K>
+where
+    IX: H3IndexArrayValue,
+    K: ArrowDictionaryKeyType,
+    H3Array<IX>: TryFrom<UInt64Array, Error = Error>,
+{
+    type Error = Error;
+
+    fn try_from(value: DictionaryArray<K>) -> Result<Self, Self::Error> {
+        if value.values().data_type() != &DataType::UInt64 {
+            return Err(Error::NotAUint64Array);
+        }
+
+        // validating the distinct values is sufficient
+        H3Array::<IX>::try_from(value.values().as_primitive::<UInt64Type>().clone())?;
+
+        Ok(Self {
+            dictionary_array: value,
+            h3index_phantom: PhantomData::<IX>,
+        })
+    }
+}
+
+impl<IX, K> TryFrom<&H3Array<IX>> for H3DictionaryArray<IX, K>
+where
+    IX: H3IndexArrayValue,
+    K: ArrowDictionaryKeyType,
+{
+    type Error = Error;
+
+    /// Fails when the number of distinct values exceeds the range of the key type.
+    fn try_from(value: &H3Array<IX>) -> Result<Self, Self::Error> {
+        let mut builder = PrimitiveDictionaryBuilder::<K, UInt64Type>::with_capacity(
+            value.len(),
+            value.len().min(1024),
+        );
+        for h3index in value.primitive_array().iter() {
+            match h3index {
+                Some(h3index) => {
+                    builder.append(h3index)?;
+                }
+                None => builder.append_null(),
+            }
+        }
+        Ok(Self {
+            dictionary_array: builder.finish(),
+            h3index_phantom: PhantomData::<IX>,
+        })
+    }
+}
+
+impl<IX, K> From<H3DictionaryArray<IX, K>> for DictionaryArray<K>
+where
+    K: ArrowDictionaryKeyType,
+{
+    fn from(value: H3DictionaryArray<IX, K>) -> Self {
+        value.dictionary_array
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow::array::{DictionaryArray, UInt64Array};
+    use arrow::datatypes::{Int32Type, Int8Type};
+    use h3o::{CellIndex, LatLng, Resolution};
+
+    use crate::array::to_geo::ToPolygons;
+    use crate::array::{CellIndexArray, H3DictionaryArray};
+
+    fn some_cells() -> CellIndexArray {
+        let cell_a = LatLng::new(23.4, 12.4).unwrap().to_cell(Resolution::Five);
+        let cell_b = LatLng::new(12.3, 0.5).unwrap().to_cell(Resolution::Nine);
+        vec![Some(cell_a), None, Some(cell_b), Some(cell_a), Some(cell_a)].into()
+    }
+
+    #[test]
+    fn roundtrip() {
+        let cells = some_cells();
+        let dict = H3DictionaryArray::<CellIndex>::try_from(&cells).unwrap();
+        assert_eq!(dict.len(), 5);
+        assert_eq!(dict.values().len(), 2);
+        assert_eq!(
+            dict.iter().collect::<Vec<_>>(),
+            cells.iter().collect::<Vec<_>>()
+        );
+
+        let decoded = dict.to_h3array().unwrap();
+        assert_eq!(
+            decoded.iter().collect::<Vec<_>>(),
+            cells.iter().collect::<Vec<_>>()
+        );
+
+        let dict = H3DictionaryArray::<CellIndex, Int8Type>::try_from(&cells).unwrap();
+        assert_eq!(dict.keys().len(), 5);
+    }
+
+    #[test]
+    fn validate() {
+        let cells = some_cells();
+        let dict = H3DictionaryArray::<CellIndex>::try_from(&cells).unwrap();
+        let dictionary_array: DictionaryArray<Int32Type> = dict.into();
+        assert!(H3DictionaryArray::<CellIndex>::try_from(dictionary_array).is_ok());
+
+        let invalid = DictionaryArray::<Int32Type>::try_new(
+            vec![0, 0].into(),
+            std::sync::Arc::new(UInt64Array::from(vec![0])),
+        )
+        .unwrap();
+        assert!(H3DictionaryArray::<CellIndex>::try_from(invalid).is_err());
+    }
+
+    #[test]
+    fn to_polygons() {
+        let cells = some_cells();
+        let dict = H3DictionaryArray::<CellIndex>::try_from(&cells).unwrap();
+        assert_eq!(
+            dict.to_polygons(true).unwrap(),
+            cells.to_polygons(true).unwrap()
+        );
+    }
+
+    #[cfg(feature = "geoarrow")]
+    #[test]
+    fn to_wkb_polygons() {
+        use crate::array::to_geoarrow::ToWKBPolygons;
+        use arrow::array::Array;
+
+        let cells = some_cells();
+        let dict = H3DictionaryArray::<CellIndex>::try_from(&cells).unwrap();
+        let wkbs = dict.to_wkb_polygons::<i32>(true).unwrap().into_inner();
+        let expected = cells.to_wkb_polygons::<i32>(true).unwrap().into_inner();
+        assert_eq!(wkbs.len(), 5);
+        assert!(wkbs.is_null(1));
+        assert_eq!(wkbs, expected);
+    }
+}
@@ -1,7 +1,26 @@
-use crate::array::{CellIndexArray, DirectedEdgeIndexArray};
+use crate::array::{check_same_length, CellIndexArray, DirectedEdgeIndexArray};
+use crate::error::Error;
 use arrow::array::Float64Array;
 
 impl DirectedEdgeIndexArray {
+    /// The edges from each of the `origins` to the destination at the same position.
+    ///
+    /// The value is null when the cells are not neighbors.
+    pub fn from_cell_pairs(
+        origins: &CellIndexArray,
+        destinations: &CellIndexArray,
+    ) -> Result<Self, Error> {
+        check_same_length(origins.len(), destinations.len())?;
+        Ok(origins
+            .iter()
+            .zip(destinations.iter())
+            .map(|(origin, destination)| match (origin, destination) {
+                (Some(origin), Some(destination)) => origin.edge(destination),
+                _ => None,
+            })
+            .collect())
+    }
+
     pub fn origin(&self) -> CellIndexArray {
         self.iter()
             .map(|edge| edge.map(|edge| edge.origin()))
@@ -32,3 +51,34 @@ impl DirectedEdgeIndexArray {
             .collect()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use h3o::{LatLng, Resolution};
+
+    use crate::array::{CellIndexArray, DirectedEdgeIndexArray};
+    use crate::error::Error;
+
+    #[test]
+    fn from_cell_pairs() {
+        let cell = LatLng::new(23.4, 12.4).unwrap().to_cell(Resolution::Five);
+        let neighbor = cell.grid_ring_fast(1).flatten().next().unwrap();
+        let far_away = LatLng::new(12.3, 0.5).unwrap().to_cell(Resolution::Five);
+
+        let origins: CellIndexArray = vec![Some(cell), Some(cell), None].into();
+        let destinations: CellIndexArray = vec![Some(neighbor), Some(far_away), Some(cell)].into();
+
+        let edges = DirectedEdgeIndexArray::from_cell_pairs(&origins, &destinations).unwrap();
+        assert_eq!(edges.len(), 3);
+        assert_eq!(edges.get(0), cell.edge(neighbor));
+        assert_eq!(edges.get(1), None);
+        assert_eq!(edges.get(2), None);
+        assert_eq!(edges.origin().get(0), Some(cell));
+        assert_eq!(edges.destination().get(0), Some(neighbor));
+
+        assert!(matches!(
+            DirectedEdgeIndexArray::from_cell_pairs(&origins, &destinations.slice(0, 2)),
+            Err(Error::LengthMismatch(3, 2))
+        ));
+    }
+}
@@ -0,0 +1,142 @@
+use std::collections::HashMap;
+
+use arrow::array::{Array, ArrayRef, UInt64Array};
+use arrow::datatypes::Field;
+
+use crate::array::{H3Array, H3IndexArrayValue};
+use crate::error::Error;
+
+/// Metadata key of the field metadata holding the name of an arrow extension type.
+pub const EXTENSION_NAME_KEY: &str = "ARROW:extension:name";
+
+/// Field metadata registering the arrow extension type of the H3 index type `IX`.
+pub fn extension_metadata<IX: H3IndexArrayValue>() -> HashMap<String, String> {
+    HashMap::from([(
+        EXTENSION_NAME_KEY.to_string(),
+        IX::EXTENSION_NAME.to_string(),
+    )])
+}
+
+impl<IX> H3Array<IX>
+where
+    IX: H3IndexArrayValue,
+{
+    /// Build a [`Field`] for this array which is tagged with the arrow extension type
+    /// of the contained H3 indexes.
+    ///
+    /// The field is always nullable, like the one of [`H3Array::to_field`].
+    pub fn extension_field(&self, name: &str) -> Field {
+        self.to_field(name)
+            .with_metadata(extension_metadata::<IX>())
+    }
+}
+
+impl<IX> TryFrom<(&Field, &ArrayRef)> for H3Array<IX>
+where
+    IX: H3IndexArrayValue,
+    H3Array<IX>: TryFrom<UInt64Array, Error = Error>,
+{
+    type Error = Error;
+
+    /// Accepts the column only when the field is tagged with the extension type of `IX`. The
+    /// values are validated.
+    fn try_from((field, array): (&Field, &ArrayRef)) -> Result<Self, Self::Error> {
+        let found = field.metadata().get(EXTENSION_NAME_KEY);
+        if found.map(String::as_str) != Some(IX::EXTENSION_NAME) {
+            return Err(Error::ExtensionTypeMismatch {
+                expected: IX::EXTENSION_NAME,
+                found: found.cloned(),
+            });
+        }
+
+        array
+            .as_any()
+            .downcast_ref::<UInt64Array>()
+            .ok_or(Error::NotAUint64Array)?
+            .clone()
+            .try_into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use arrow::array::{ArrayRef, RecordBatch};
+    use arrow::datatypes::{DataType, Field, Schema};
+    use h3o::{LatLng, Resolution};
+
+    use crate::array::{CellIndexArray, VertexIndexArray, EXTENSION_NAME_KEY};
+    use crate::error::Error;
+
+    fn some_cells() -> CellIndexArray {
+        vec![
+            Some(LatLng::new(23.4, 12.4).unwrap().to_cell(Resolution::Five)),
+            None,
+        ]
+        .into()
+    }
+
+    #[test]
+    fn recordbatch_roundtrip() {
+        let cells = some_cells();
+        let field = cells.extension_field("cell");
+        assert_eq!(
+            field.metadata().get(EXTENSION_NAME_KEY).map(String::as_str),
+            Some("h3.cell")
+        );
+        assert!(field.is_nullable());
+
+        let batch = RecordBatch::try_new(
+            Arc::new(Schema::new(vec![field])),
+            vec![cells.clone().into_array_ref()],
+        )
+        .unwrap();
+
+        let schema = batch.schema();
+        let cells2 = CellIndexArray::try_from((schema.field(0), batch.column(0))).unwrap();
+        assert!(cells == cells2);
+    }
+
+    #[test]
+    fn plain_field() {
+        let cells = some_cells();
+        let field = cells.to_field("cell");
+        assert_eq!(field.data_type(), &DataType::UInt64);
+        assert!(field.is_nullable());
+        assert!(field.metadata().is_empty());
+
+        // the schema does not depend on the contained values
+        let valid_cells = cells.slice(0, 1);
+        assert_eq!(valid_cells.to_field("cell"), field);
+        let batch = RecordBatch::try_new(
+            Arc::new(Schema::new(vec![valid_cells.to_field("cell")])),
+            vec![valid_cells.into()],
+        )
+        .unwrap();
+        assert!(batch.schema().field(0).is_nullable());
+    }
+
+    #[test]
+    fn mismatching_extension_type_fails() {
+        let cells = some_cells();
+        let field = cells.extension_field("cell");
+        let array = ArrayRef::from(cells);
+
+        assert!(matches!(
+            VertexIndexArray::try_from((&field, &array)),
+            Err(Error::ExtensionTypeMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn missing_extension_type_fails() {
+        let field = Field::new("cell", DataType::UInt64, true);
+        let array = ArrayRef::from(some_cells());
+
+        assert!(matches!(
+            CellIndexArray::try_from((&field, &array)),
+            Err(Error::ExtensionTypeMismatch { found: None, .. })
+        ));
+    }
+}
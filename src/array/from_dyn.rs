@@ -0,0 +1,116 @@
+use arrow::array::{Array, AsArray, UInt64Array};
+use arrow::compute::cast;
+use arrow::datatypes::{DataType, Int64Type, UInt64Type};
+
+use crate::algorithm::ParseGenericStringArray;
+use crate::array::{ByteOrder, H3Array, H3IndexArrayValue};
+use crate::error::Error;
+
+impl<IX> H3Array<IX>
+where
+    IX: H3IndexArrayValue,
+    H3Array<IX>: TryFrom<UInt64Array, Error = Error> + ParseGenericStringArray,
+{
+    /// Build a validated array from an arrow array of any of the supported physical types.
+    ///
+    /// * `UInt64`
+    /// * `Int64`. The values are reinterpreted without copying, negative values cause an error.
+    /// * `Utf8` and `LargeUtf8`. Parsed using [`ParseGenericStringArray`], unparsable values
+    ///   cause an error.
+    /// * `FixedSizeBinary(8)`. The bytes are expected in big-endian order.
+    /// * Dictionary-encoded arrays of any of the above types.
+    ///
+    /// All other data types result in [`Error::UnsupportedDataType`].
+    pub fn try_from_dyn(array: &dyn Array) -> Result<Self, Error> {
+        match array.data_type() {
+            DataType::UInt64 => array.as_primitive::<UInt64Type>().clone().try_into(),
+            DataType::Int64 => array.as_primitive::<Int64Type>().clone().try_into(),
+            DataType::Utf8 => Self::parse_genericstringarray(array.as_string::<i32>(), false),
+            DataType::LargeUtf8 => Self::parse_genericstringarray(array.as_string::<i64>(), false),
+            DataType::FixedSizeBinary(8) => Self::try_from_fixedsizebinaryarray(
+                array.as_fixed_size_binary(),
+                ByteOrder::BigEndian,
+            ),
+            DataType::Dictionary(_, value_type) => {
+                Self::try_from_dyn(cast(array, value_type)?.as_ref())
+            }
+            data_type => Err(Error::UnsupportedDataType(data_type.clone())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow::array::{
+        Array, DictionaryArray, FixedSizeBinaryArray, Float64Array, Int64Array, LargeStringArray,
+        StringArray, UInt64Array,
+    };
+    use arrow::datatypes::Int32Type;
+    use h3o::{CellIndex, LatLng, Resolution};
+
+    use crate::array::CellIndexArray;
+    use crate::error::Error;
+
+    fn some_cell() -> CellIndex {
+        LatLng::new(23.4, 12.4).unwrap().to_cell(Resolution::Five)
+    }
+
+    fn assert_cells(arr: &dyn Array) {
+        let cells = CellIndexArray::try_from_dyn(arr).unwrap();
+        assert_eq!(cells.len(), 2);
+        assert_eq!(cells.get(0), Some(some_cell()));
+        assert_eq!(cells.get(1), None);
+    }
+
+    #[test]
+    fn from_uint64() {
+        assert_cells(&UInt64Array::from(vec![Some(u64::from(some_cell())), None]));
+    }
+
+    #[test]
+    fn from_int64() {
+        assert_cells(&Int64Array::from(vec![
+            Some(u64::from(some_cell()) as i64),
+            None,
+        ]));
+        assert!(CellIndexArray::try_from_dyn(&Int64Array::from(vec![-1])).is_err());
+    }
+
+    #[test]
+    fn from_strings() {
+        assert_cells(&StringArray::from(vec![
+            Some(some_cell().to_string()),
+            None,
+        ]));
+        assert_cells(&LargeStringArray::from(vec![
+            Some(some_cell().to_string()),
+            None,
+        ]));
+    }
+
+    #[test]
+    fn from_dictionary() {
+        let cell_string = some_cell().to_string();
+        let dict: DictionaryArray<Int32Type> =
+            vec![Some(cell_string.as_str()), None].into_iter().collect();
+        assert_cells(&dict);
+    }
+
+    #[test]
+    fn from_fixedsizebinary() {
+        let arr = FixedSizeBinaryArray::try_from_sparse_iter_with_size(
+            vec![Some(u64::from(some_cell()).to_be_bytes()), None].into_iter(),
+            8,
+        )
+        .unwrap();
+        assert_cells(&arr);
+    }
+
+    #[test]
+    fn unsupported_datatype() {
+        assert!(matches!(
+            CellIndexArray::try_from_dyn(&Float64Array::from(vec![1.0])),
+            Err(Error::UnsupportedDataType(_))
+        ));
+    }
+}
@@ -7,7 +7,7 @@ use h3o::{CellIndex, Resolution};
 use rayon::prelude::{IntoParallelIterator, ParallelIterator};
 
 use crate::array::list::H3ListArray;
-use crate::array::{CellIndexArray, H3ListArrayBuilder};
+use crate::array::{CellIndexArray, H3ListArrayBuilder, RowIndexedH3Array};
 use crate::error::Error;
 
 #[derive(Clone, Copy, Debug)]
@@ -105,15 +105,35 @@ impl_to_cloned!(
 /// convert to a single `CellIndexArray`
 pub trait ToCellIndexArray {
     fn to_cellindexarray(&self, options: &ToCellsOptions) -> Result<CellIndexArray, Error>;
+
+    /// Convert to a single `CellIndexArray` while keeping track of the position of the geometry
+    /// each cell originates from.
+    ///
+    /// When `compact` is set, the cells are compacted per geometry. No compaction happens across
+    /// geometries.
+    fn to_cellindexarray_with_row_indices(
+        &self,
+        options: &ToCellsOptions,
+    ) -> Result<RowIndexedH3Array<CellIndex>, Error>;
 }
 
 pub(crate) trait IterToCellIndexArray {
     fn to_cellindexarray(self, options: &ToCellsOptions) -> Result<CellIndexArray, Error>;
+
+    fn to_cellindexarray_with_row_indices(
+        self,
+        options: &ToCellsOptions,
+    ) -> Result<RowIndexedH3Array<CellIndex>, Error>;
 }
 
 #[cfg(feature = "rayon")]
 pub(crate) trait ParIterToCellIndexArray {
     fn par_to_cellindexarray(self, options: &ToCellsOptions) -> Result<CellIndexArray, Error>;
+
+    fn par_to_cellindexarray_with_row_indices(
+        self,
+        options: &ToCellsOptions,
+    ) -> Result<RowIndexedH3Array<CellIndex>, Error>;
 }
 
 #[cfg(feature = "rayon")]
@@ -139,6 +159,18 @@ where
             })?;
         Ok(cells.into())
     }
+
+    fn par_to_cellindexarray_with_row_indices(
+        self,
+        options: &ToCellsOptions,
+    ) -> Result<RowIndexedH3Array<CellIndex>, Error> {
+        // collecting preserves the order of the geometries
+        let cell_vecs = self
+            .map(|geom| geom.map(|geom| to_cells(geom, options, vec![])).transpose())
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(cell_vecs_to_rowindexedarray(cell_vecs))
+    }
 }
 
 impl<T> IterToCellIndexArray for T
@@ -155,6 +187,26 @@ where
         })?;
         Ok(cells.into())
     }
+
+    fn to_cellindexarray_with_row_indices(
+        self,
+        options: &ToCellsOptions,
+    ) -> Result<RowIndexedH3Array<CellIndex>, Error> {
+        let mut cells = vec![];
+        let mut row_indices = vec![];
+        for (row_i, geom) in self.enumerate() {
+            if let Some(geom) = geom {
+                let len_before = cells.len();
+                cells = to_cells(geom, options, cells)?;
+                #[allow(clippy::manual_repeat_n)]
+                row_indices.extend(std::iter::repeat(row_i as u64).take(cells.len() - len_before));
+            }
+        }
+        Ok(RowIndexedH3Array {
+            values: cells.into(),
+            row_indices: row_indices.into(),
+        })
+    }
 }
 
 #[cfg(feature = "rayon")]
@@ -167,6 +219,15 @@ where
             .map(|v| v.to_cloned_geometry())
             .par_to_cellindexarray(options)
     }
+
+    fn to_cellindexarray_with_row_indices(
+        &self,
+        options: &ToCellsOptions,
+    ) -> Result<RowIndexedH3Array<CellIndex>, Error> {
+        self.into_par_iter()
+            .map(|v| v.to_cloned_geometry())
+            .par_to_cellindexarray_with_row_indices(options)
+    }
 }
 
 #[cfg(not(feature = "rayon"))]
@@ -179,6 +240,15 @@ where
             .map(|v| v.to_cloned_geometry())
             .to_cellindexarray(options)
     }
+
+    fn to_cellindexarray_with_row_indices(
+        &self,
+        options: &ToCellsOptions,
+    ) -> Result<RowIndexedH3Array<CellIndex>, Error> {
+        self.iter()
+            .map(|v| v.to_cloned_geometry())
+            .to_cellindexarray_with_row_indices(options)
+    }
 }
 
 pub trait ToCellListArray<O: OffsetSizeTrait> {
@@ -218,6 +288,7 @@ where
     }
 }
 
+#[cfg(any(feature = "rayon", feature = "geoarrow"))]
 pub(crate) fn cell_vecs_to_h3listarray<O: OffsetSizeTrait>(
     cell_vecs: Vec<Option<Vec<CellIndex>>>,
 ) -> Result<H3ListArray<CellIndex, O>, Error> {
@@ -240,6 +311,27 @@ pub(crate) fn cell_vecs_to_h3listarray<O: OffsetSizeTrait>(
     builder.finish()
 }
 
+#[cfg(any(feature = "rayon", feature = "geoarrow"))]
+pub(crate) fn cell_vecs_to_rowindexedarray(
+    cell_vecs: Vec<Option<Vec<CellIndex>>>,
+) -> RowIndexedH3Array<CellIndex> {
+    let capacity: usize = cell_vecs.iter().flatten().map(|cells| cells.len()).sum();
+
+    let mut cells = Vec::with_capacity(capacity);
+    let mut row_indices = Vec::with_capacity(capacity);
+    for (row_i, row_cells) in cell_vecs.into_iter().enumerate() {
+        if let Some(mut row_cells) = row_cells {
+            #[allow(clippy::manual_repeat_n)]
+            row_indices.extend(std::iter::repeat(row_i as u64).take(row_cells.len()));
+            cells.append(&mut row_cells);
+        }
+    }
+    RowIndexedH3Array {
+        values: cells.into(),
+        row_indices: row_indices.into(),
+    }
+}
+
 impl<T, O: OffsetSizeTrait> IterToCellListArray<O> for T
 where
     T: Iterator<Item = Option<Geometry>>,
@@ -327,10 +419,51 @@ fn to_cells(
 }
 
 #[cfg(test)]
-mod tests {
-    use crate::array::from_geo::{ToCellIndexArray, ToCellsOptions};
-    use geo_types::Rect;
-    use h3o::Resolution;
+pub(crate) mod tests {
+    use crate::array::from_geo::{geometry_to_cells, ToCellIndexArray, ToCellsOptions};
+    use crate::array::RowIndexedH3Array;
+    use geo_types::{Geometry, Polygon, Rect};
+    use h3o::{CellIndex, Resolution};
+
+    /// Polygons including a null row and a row too small to contain any cell at resolution 4.
+    pub(crate) fn polygons_with_empty_rows() -> Vec<Option<Polygon>> {
+        vec![
+            Some(Rect::new((10., 10.), (20., 20.)).to_polygon()),
+            None,
+            Some(Rect::new((30., 10.), (30.001, 10.001)).to_polygon()),
+            Some(Rect::new((30., 10.), (40., 20.)).to_polygon()),
+        ]
+    }
+
+    /// Assert that the cells of each row of `polygons` are tagged with the index of that row.
+    pub(crate) fn assert_row_indices(
+        polygons: &[Option<Polygon>],
+        options: &ToCellsOptions,
+        cells: &RowIndexedH3Array<CellIndex>,
+    ) {
+        let mut expected_cells = vec![];
+        let mut expected_row_indices = vec![];
+        for (row_i, polygon) in polygons.iter().enumerate() {
+            if let Some(polygon) = polygon {
+                let row_cells =
+                    geometry_to_cells(&Geometry::from(polygon.clone()), options).unwrap();
+                #[allow(clippy::manual_repeat_n)]
+                expected_row_indices.extend(std::iter::repeat(row_i as u64).take(row_cells.len()));
+                expected_cells.extend(row_cells);
+            }
+        }
+        assert!(!expected_cells.is_empty());
+        assert!(!expected_row_indices.contains(&2));
+
+        assert_eq!(
+            cells.values.iter().flatten().collect::<Vec<_>>(),
+            expected_cells
+        );
+        assert_eq!(
+            cells.row_indices.iter().flatten().collect::<Vec<_>>(),
+            expected_row_indices
+        );
+    }
 
     #[test]
     fn from_rect() {
@@ -345,4 +478,42 @@ mod tests {
             assert_eq!(r, Some(Resolution::Four));
         }
     }
+
+    #[test]
+    fn from_rects_with_row_indices() {
+        let rects = vec![
+            Some(Rect::new((10., 10.), (20., 20.))),
+            None,
+            Some(Rect::new((30., 10.), (40., 20.))),
+        ];
+        let options = ToCellsOptions::from(Resolution::Four);
+        let cells = rects
+            .as_slice()
+            .to_cellindexarray_with_row_indices(&options)
+            .unwrap();
+
+        assert_eq!(cells.values.len(), cells.row_indices.len());
+        let first_row_len = cells
+            .row_indices
+            .iter()
+            .take_while(|row_i| *row_i == Some(0))
+            .count();
+        assert!(first_row_len > 400);
+        assert!(cells
+            .row_indices
+            .iter()
+            .skip(first_row_len)
+            .all(|row_i| row_i == Some(2)));
+    }
+
+    #[test]
+    fn with_row_indices_null_and_empty_rows() {
+        let polygons = polygons_with_empty_rows();
+        let options = ToCellsOptions::from(Resolution::Four);
+        let cells = polygons
+            .as_slice()
+            .to_cellindexarray_with_row_indices(&options)
+            .unwrap();
+        assert_row_indices(&polygons, &options, &cells);
+    }
 }
@@ -1,10 +1,10 @@
 use super::from_geo::{
-    cell_vecs_to_h3listarray, IterToCellIndexArray, IterToCellListArray, ToCellIndexArray,
-    ToCellListArray, ToCellsOptions,
+    cell_vecs_to_h3listarray, cell_vecs_to_rowindexedarray, IterToCellIndexArray,
+    IterToCellListArray, ToCellIndexArray, ToCellListArray, ToCellsOptions,
 };
 use crate::algorithm::CompactOp;
 use crate::array::from_geo::geometry_to_cells;
-use crate::array::{CellIndexArray, H3ListArray};
+use crate::array::{CellIndexArray, H3ListArray, RowIndexedH3Array};
 use crate::error::Error;
 use arrow::array::OffsetSizeTrait;
 use geo_types::Geometry;
@@ -22,18 +22,27 @@ macro_rules! impl_to_cells {
                 &self,
                 options: &ToCellsOptions,
             ) -> Result<H3ListArray<CellIndex, $offset>, Error> {
-                self.iter_geo()
-                    .map(|v| v.map(Geometry::from))
+                (0..self.len())
+                    .map(|pos| self.get_as_geo(pos).map(Geometry::from))
                     .to_celllistarray(options)
             }
         }
 
         impl<$offset: OffsetSizeTrait> ToCellIndexArray for $array_type {
             fn to_cellindexarray(&self, options: &ToCellsOptions) -> Result<CellIndexArray, Error> {
-                self.iter_geo()
-                    .map(|v| v.map(Geometry::from))
+                (0..self.len())
+                    .map(|pos| self.get_as_geo(pos).map(Geometry::from))
                     .to_cellindexarray(options)
             }
+
+            fn to_cellindexarray_with_row_indices(
+                &self,
+                options: &ToCellsOptions,
+            ) -> Result<RowIndexedH3Array<CellIndex>, Error> {
+                (0..self.len())
+                    .map(|pos| self.get_as_geo(pos).map(Geometry::from))
+                    .to_cellindexarray_with_row_indices(options)
+            }
         }
     };
     ($array_type:ty) => {
@@ -42,18 +51,27 @@ macro_rules! impl_to_cells {
                 &self,
                 options: &ToCellsOptions,
             ) -> Result<H3ListArray<CellIndex, O>, Error> {
-                self.iter_geo()
-                    .map(|v| v.map(Geometry::from))
+                (0..self.len())
+                    .map(|pos| self.get_as_geo(pos).map(Geometry::from))
                     .to_celllistarray(options)
             }
         }
 
         impl ToCellIndexArray for $array_type {
             fn to_cellindexarray(&self, options: &ToCellsOptions) -> Result<CellIndexArray, Error> {
-                self.iter_geo()
-                    .map(|v| v.map(Geometry::from))
+                (0..self.len())
+                    .map(|pos| self.get_as_geo(pos).map(Geometry::from))
                     .to_cellindexarray(options)
             }
+
+            fn to_cellindexarray_with_row_indices(
+                &self,
+                options: &ToCellsOptions,
+            ) -> Result<RowIndexedH3Array<CellIndex>, Error> {
+                (0..self.len())
+                    .map(|pos| self.get_as_geo(pos).map(Geometry::from))
+                    .to_cellindexarray_with_row_indices(options)
+            }
         }
     };
 }
@@ -65,26 +83,32 @@ impl_to_cells!(geoarrow::array::MultiPolygonArray<O>, O);
 impl_to_cells!(geoarrow::array::PointArray);
 impl_to_cells!(geoarrow::array::PolygonArray<O>, O);
 
+fn wkbarray_to_cell_vecs<O: OffsetSizeTrait>(
+    array: &WKBArray<O>,
+    options: &ToCellsOptions,
+) -> Result<Vec<Option<Vec<CellIndex>>>, Error> {
+    #[cfg(not(feature = "rayon"))]
+    let pos_iter = 0..array.len();
+
+    #[cfg(feature = "rayon")]
+    let pos_iter = (0..array.len()).into_par_iter();
+
+    pos_iter
+        .map(|pos| {
+            array
+                .get_as_geo(pos)
+                .map(|geom| geometry_to_cells(&geom, options))
+                .transpose()
+        })
+        .collect()
+}
+
 impl<O: OffsetSizeTrait> ToCellListArray<O> for WKBArray<O> {
     fn to_celllistarray(
         &self,
         options: &ToCellsOptions,
     ) -> Result<H3ListArray<CellIndex, O>, Error> {
-        #[cfg(not(feature = "rayon"))]
-        let pos_iter = 0..self.len();
-
-        #[cfg(feature = "rayon")]
-        let pos_iter = (0..self.len()).into_par_iter();
-
-        let cell_vecs = pos_iter
-            .map(|pos| {
-                self.get_as_geo(pos)
-                    .map(|geom| geometry_to_cells(&geom, options))
-                    .transpose()
-            })
-            .collect::<Result<Vec<_>, _>>()?;
-
-        cell_vecs_to_h3listarray(cell_vecs)
+        cell_vecs_to_h3listarray(wkbarray_to_cell_vecs(self, options)?)
     }
 }
 
@@ -98,4 +122,39 @@ impl<O: OffsetSizeTrait> ToCellIndexArray for WKBArray<O> {
             Ok(cellindexarray) // may contain duplicates
         }
     }
+
+    fn to_cellindexarray_with_row_indices(
+        &self,
+        options: &ToCellsOptions,
+    ) -> Result<RowIndexedH3Array<CellIndex>, Error> {
+        Ok(cell_vecs_to_rowindexedarray(wkbarray_to_cell_vecs(
+            self, options,
+        )?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::array::from_geo::tests::{assert_row_indices, polygons_with_empty_rows};
+    use crate::array::from_geo::{ToCellIndexArray, ToCellsOptions};
+    use geoarrow::array::{PolygonArray, WKBArray};
+    use h3o::Resolution;
+
+    #[test]
+    fn polygonarray_with_row_indices() {
+        let polygons = polygons_with_empty_rows();
+        let options = ToCellsOptions::from(Resolution::Four);
+        let array: PolygonArray<i32> = polygons.clone().into();
+        let cells = array.to_cellindexarray_with_row_indices(&options).unwrap();
+        assert_row_indices(&polygons, &options, &cells);
+    }
+
+    #[test]
+    fn wkbarray_with_row_indices() {
+        let polygons = polygons_with_empty_rows();
+        let options = ToCellsOptions::from(Resolution::Four);
+        let array: WKBArray<i32> = (&PolygonArray::<i32>::from(polygons.clone())).into();
+        let cells = array.to_cellindexarray_with_row_indices(&options).unwrap();
+        assert_row_indices(&polygons, &options, &cells);
+    }
 }
@@ -0,0 +1,98 @@
+use arrow::array::{Array, Int64Array, UInt64Array};
+use arrow::buffer::ScalarBuffer;
+
+use crate::array::{FromIteratorWithValidity, FromWithValidity, H3Array, H3IndexArrayValue};
+use crate::error::Error;
+
+// Many databases store H3 indexes as signed `BIGINT`. As the highest bit of a valid H3 index is
+// always unset, both representations share the same bit pattern, so the buffers can be
+// reinterpreted without copying.
+
+fn int64array_to_uint64array(value: &Int64Array) -> UInt64Array {
+    UInt64Array::new(
+        ScalarBuffer::from(value.values().inner().clone()),
+        value.nulls().cloned(),
+    )
+}
+
+fn uint64array_to_int64array(value: &UInt64Array) -> Int64Array {
+    Int64Array::new(
+        ScalarBuffer::from(value.values().inner().clone()),
+        value.nulls().cloned(),
+    )
+}
+
+impl<IX> H3Array<IX>
+where
+    IX: H3IndexArrayValue,
+{
+    /// Reinterpret the contained values as signed integers without copying them.
+    pub fn to_int64array(&self) -> Int64Array {
+        uint64array_to_int64array(&self.primitive_array)
+    }
+}
+
+impl<IX> TryFrom<Int64Array> for H3Array<IX>
+where
+    IX: H3IndexArrayValue,
+    H3Array<IX>: TryFrom<UInt64Array, Error = Error>,
+{
+    type Error = Error;
+
+    fn try_from(value: Int64Array) -> Result<Self, Self::Error> {
+        int64array_to_uint64array(&value).try_into()
+    }
+}
+
+impl<IX> FromWithValidity<Int64Array> for H3Array<IX>
+where
+    IX: H3IndexArrayValue,
+{
+    fn from_with_validity(value: Int64Array) -> Self {
+        Self::from_iter_with_validity(int64array_to_uint64array(&value).iter())
+    }
+}
+
+impl<IX> From<H3Array<IX>> for Int64Array
+where
+    IX: H3IndexArrayValue,
+{
+    fn from(value: H3Array<IX>) -> Self {
+        value.to_int64array()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow::array::{Array, Int64Array};
+    use h3o::{LatLng, Resolution};
+
+    use crate::array::{CellIndexArray, FromWithValidity};
+
+    #[test]
+    fn int64_roundtrip() {
+        let cells: CellIndexArray = vec![
+            Some(LatLng::new(23.4, 12.4).unwrap().to_cell(Resolution::Five)),
+            None,
+        ]
+        .into();
+
+        let int64array = Int64Array::from(cells.clone());
+        assert_eq!(int64array.len(), 2);
+        assert!(int64array.is_null(1));
+
+        let cells2 = CellIndexArray::try_from(int64array).unwrap();
+        assert!(cells == cells2);
+    }
+
+    #[test]
+    fn int64_invalid() {
+        let int64array = Int64Array::from(vec![-1, 0x85283473fffffff]);
+        assert!(CellIndexArray::try_from(int64array.clone()).is_err());
+
+        let cells = CellIndexArray::from_with_validity(int64array);
+        assert_eq!(cells.len(), 2);
+        assert!(cells.get(0).is_none());
+        assert!(cells.get(1).is_some());
+    }
+}
@@ -1,10 +1,18 @@
 use crate::array::{H3Array, H3IndexArrayValue};
 use crate::error::Error;
-use arrow::array::{Array, GenericListBuilder, UInt64Array, UInt64Builder};
+use arrow::array::{Array, AsArray, GenericListBuilder, UInt64Array, UInt64Builder};
 use arrow::array::{GenericListArray, OffsetSizeTrait};
-use arrow::datatypes::DataType;
+use arrow::buffer::OffsetBuffer;
+use arrow::compute;
+use arrow::datatypes::{DataType, UInt64Type};
 use std::marker::PhantomData;
 
+/// Values together with the indices of the rows they originate from.
+pub struct RowIndexedH3Array<IX> {
+    pub values: H3Array<IX>,
+    pub row_indices: UInt64Array,
+}
+
 pub struct H3ListArray<IX, O: OffsetSizeTrait = i64> {
     pub(crate) list_array: GenericListArray<O>,
     pub(crate) h3index_phantom: PhantomData<IX>,
@@ -27,42 +35,98 @@ where
         self.list_array.is_empty()
     }
 
+    /// The offsets of the lists into the values of the list array.
+    pub fn offsets(&self) -> &OffsetBuffer<O> {
+        self.list_array.offsets()
+    }
+
+    /// The number of values in the list at position `i`.
+    pub fn value_length(&self, i: usize) -> O {
+        self.list_array.value_length(i)
+    }
+
+    /// The values of the list at position `i`.
+    ///
+    /// The values have already been validated, so they are not validated again.
+    pub fn value(&self, i: usize) -> H3Array<IX> {
+        H3Array::from_primitive_array_unvalidated(
+            self.list_array
+                .value(i)
+                .as_primitive::<UInt64Type>()
+                .clone(),
+        )
+    }
+
     pub fn iter_arrays(&self) -> impl Iterator<Item = Option<Result<H3Array<IX>, Error>>> + '_ {
         self.list_array.iter().map(|opt| {
             opt.map(|array| {
                 array
                     .as_any()
                     .downcast_ref::<UInt64Array>()
-                    // TODO: this should already be validated. unwrap/expect?
                     .ok_or(Error::NotAUint64Array)
-                    .and_then(|pa| pa.clone().try_into())
+                    // the values have already been validated
+                    .map(|pa| H3Array::from_primitive_array_unvalidated(pa.clone()))
             })
         })
     }
 
+    /// The values of all non-null lists.
     pub fn into_flattened(self) -> Result<H3Array<IX>, Error> {
-        // TODO: check validity correctness
-        self.list_array
-            .values()
-            .as_any()
-            .downcast_ref::<UInt64Array>()
-            // TODO: this should already be validated. unwrap/expect?
-            .ok_or(Error::NotAUint64Array)
-            .and_then(|pa| pa.clone().try_into())
+        Ok(self.explode()?.values)
     }
 
-    pub(crate) fn from_genericlistarray_unvalidated(
-        value: GenericListArray<O>,
-    ) -> Result<H3ListArray<IX, O>, Error> {
-        if value.data_type() != &DataType::UInt64 {
-            return Err(Error::NotAUint64Array);
+    /// Flatten the lists while keeping track of the row each value originates from.
+    ///
+    /// Null and empty lists do not contribute any values. The `row_indices` of the result
+    /// can be used with `arrow::compute::take` to repeat the values of other columns.
+    pub fn explode(&self) -> Result<RowIndexedH3Array<IX>, Error> {
+        let values = self.referenced_values()?;
+        let offsets = self.list_array.offsets();
+        let start = offsets[0].as_usize();
+
+        let mut row_indices = Vec::with_capacity(values.len());
+        let mut value_indices = Vec::new();
+        for (row_i, window) in offsets.windows(2).enumerate() {
+            if self.list_array.is_null(row_i) {
+                continue;
+            }
+            let (list_start, list_end) = (window[0].as_usize(), window[1].as_usize());
+            #[allow(clippy::manual_repeat_n)]
+            row_indices.extend(std::iter::repeat(row_i as u64).take(list_end - list_start));
+            if self.list_array.null_count() > 0 {
+                value_indices.extend((list_start - start) as u64..(list_end - start) as u64);
+            }
         }
 
-        Ok(Self {
-            list_array: value,
-            h3index_phantom: PhantomData::<IX>,
+        let values = if self.list_array.null_count() > 0 {
+            // skip the values of the null lists
+            compute::take(&values, &UInt64Array::from(value_indices), None)?
+                .as_primitive::<UInt64Type>()
+                .clone()
+        } else {
+            values
+        };
+
+        Ok(RowIndexedH3Array {
+            values: H3Array::from_primitive_array_unvalidated(values),
+            row_indices: row_indices.into(),
         })
     }
+
+    /// The values of the list array which are referenced by the offsets.
+    fn referenced_values(&self) -> Result<UInt64Array, Error> {
+        let values = self
+            .list_array
+            .values()
+            .as_any()
+            .downcast_ref::<UInt64Array>()
+            .ok_or(Error::NotAUint64Array)?;
+
+        let offsets = self.list_array.offsets();
+        let start = offsets[0].as_usize();
+        let end = offsets[offsets.len() - 1].as_usize();
+        Ok(values.slice(start, end - start))
+    }
 }
 
 impl<IX, O: OffsetSizeTrait> From<H3ListArray<IX, O>> for GenericListArray<O> {
@@ -97,22 +161,21 @@ where
     type Error = Error;
 
     fn try_from(value: GenericListArray<O>) -> Result<Self, Self::Error> {
-        let instance = Self::from_genericlistarray_unvalidated(value)?;
+        let instance = genericlistarray_to_h3listarray_unvalidated(value)?;
 
-        // validate all values
-        for a in instance.iter_arrays().flatten() {
-            let _ = a?;
-        }
+        // validate all values at once instead of list by list
+        H3Array::<IX>::try_from(instance.referenced_values()?)?;
         Ok(instance)
     }
 }
 
-pub struct H3ArrayBuilder<'a, IX> {
+/// Builder for the values of a single list of a [`H3ListArrayBuilder`].
+pub struct H3ListArrayValuesBuilder<'a, IX> {
     array_builder: &'a mut UInt64Builder,
     h3index_phantom: PhantomData<IX>,
 }
 
-impl<'a, IX> H3ArrayBuilder<'a, IX>
+impl<'a, IX> H3ListArrayValuesBuilder<'a, IX>
 where
     IX: H3IndexArrayValue,
 {
@@ -153,8 +216,8 @@ where
         self.builder.append(is_valid)
     }
 
-    pub fn values(&mut self) -> H3ArrayBuilder<'_, IX> {
-        H3ArrayBuilder {
+    pub fn values(&mut self) -> H3ListArrayValuesBuilder<'_, IX> {
+        H3ListArrayValuesBuilder {
             array_builder: self.builder.values(),
             h3index_phantom: self.h3index_phantom,
         }
@@ -174,9 +237,40 @@ where
     }
 }
 
+impl<IX, O: OffsetSizeTrait> FromIterator<Option<Vec<IX>>> for H3ListArray<IX, O>
+where
+    IX: H3IndexArrayValue,
+{
+    fn from_iter<T: IntoIterator<Item = Option<Vec<IX>>>>(iter: T) -> Self {
+        let iter = iter.into_iter();
+        let mut builder = H3ListArrayBuilder::<IX, O>::with_capacity(iter.size_hint().0, 10);
+        for list in iter {
+            let is_valid = list.is_some();
+            if let Some(list) = list {
+                builder.values().append_many(list);
+            }
+            builder.append(is_valid);
+        }
+        Self {
+            list_array: builder.builder.finish(),
+            h3index_phantom: PhantomData::<IX>,
+        }
+    }
+}
+
+impl<IX, O: OffsetSizeTrait> FromIterator<Vec<IX>> for H3ListArray<IX, O>
+where
+    IX: H3IndexArrayValue,
+{
+    fn from_iter<T: IntoIterator<Item = Vec<IX>>>(iter: T) -> Self {
+        iter.into_iter().map(Some).collect()
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::array::H3ListArrayBuilder;
+    use crate::array::{H3ListArray, H3ListArrayBuilder};
+    use arrow::array::{Array, GenericListBuilder, UInt64Builder};
     use h3o::{CellIndex, LatLng, Resolution};
 
     #[test]
@@ -192,15 +286,6 @@ mod tests {
 
         let list = builder.finish().unwrap();
 
-        /*
-        let list = H3ListArray::<CellIndex>::try_from_iter(
-            [Some(1), None, Some(2)]
-                .into_iter()
-                .map(|k| k.map(|k| cell.grid_disk::<Vec<_>>(k))),
-        )
-        .unwrap();
-
-         */
         assert_eq!(list.len(), 3);
         let mut list_iter = list.iter_arrays();
         assert_eq!(list_iter.next().unwrap().unwrap().unwrap().len(), 7);
@@ -212,4 +297,67 @@ mod tests {
         let cells = list.into_flattened().unwrap();
         assert_eq!(cells.len(), 26);
     }
+
+    #[test]
+    fn explode() {
+        let cell = LatLng::new(23.4, 12.4).unwrap().to_cell(Resolution::Five);
+
+        let list: H3ListArray<CellIndex> = [Some(1), None, Some(0), Some(1)]
+            .into_iter()
+            .map(|k| k.map(|k| cell.grid_disk::<Vec<_>>(k)))
+            .collect();
+
+        let exploded = list.explode().unwrap();
+        assert_eq!(exploded.values.len(), 15);
+        assert_eq!(exploded.row_indices.len(), 15);
+        assert_eq!(exploded.row_indices.value(0), 0);
+        assert_eq!(exploded.row_indices.value(7), 2);
+        assert_eq!(exploded.values.get(7), Some(cell));
+        assert_eq!(exploded.row_indices.value(14), 3);
+
+        // sliced lists
+        let sliced = H3ListArray::<CellIndex>::try_from(list.listarray().slice(2, 2)).unwrap();
+        let exploded = sliced.explode().unwrap();
+        assert_eq!(exploded.values.len(), 8);
+        assert_eq!(exploded.values.get(0), Some(cell));
+        assert_eq!(exploded.row_indices.value(0), 0);
+        assert_eq!(exploded.row_indices.value(7), 1);
+        assert_eq!(sliced.into_flattened().unwrap().len(), 8);
+    }
+
+    #[test]
+    fn from_iter() {
+        let cell = LatLng::new(23.4, 12.4).unwrap().to_cell(Resolution::Five);
+
+        let list: H3ListArray<CellIndex> = [Some(1), None, Some(2)]
+            .into_iter()
+            .map(|k| k.map(|k| cell.grid_disk::<Vec<_>>(k)))
+            .collect();
+
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.offsets().as_ref(), &[0, 7, 7, 26]);
+        assert_eq!(list.value_length(0), 7);
+        assert_eq!(list.value_length(1), 0);
+        assert_eq!(list.value(2).len(), 19);
+        assert!(list.listarray().is_null(1));
+
+        let list: H3ListArray<CellIndex, i32> = vec![vec![cell], cell.grid_disk::<Vec<_>>(1)]
+            .into_iter()
+            .collect();
+        assert_eq!(list.len(), 2);
+        assert_eq!(list.value(0).get(0), Some(cell));
+    }
+
+    #[test]
+    fn try_from_validates() {
+        let cell = LatLng::new(23.4, 12.4).unwrap().to_cell(Resolution::Five);
+        let list: H3ListArray<CellIndex> = vec![vec![cell]].into_iter().collect();
+        assert!(H3ListArray::<CellIndex>::try_from(list.listarray().clone()).is_ok());
+
+        let mut builder = GenericListBuilder::<i64, _>::new(UInt64Builder::new());
+        builder.values().append_value(u64::from(cell));
+        builder.values().append_value(0);
+        builder.append(true);
+        assert!(H3ListArray::<CellIndex>::try_from(builder.finish()).is_err());
+    }
 }
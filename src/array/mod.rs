@@ -1,48 +1,89 @@
 use std::marker::PhantomData;
 use std::mem::transmute;
+use std::sync::Arc;
 
-use arrow::array::{Array, ArrayIter, PrimitiveArray, UInt64Array};
-use h3o::{CellIndex, DirectedEdgeIndex, VertexIndex};
+use arrow::array::{Array, ArrayIter, ArrayRef, PrimitiveArray, UInt64Array};
+use arrow::datatypes::{DataType, Field};
+use h3o::{CellIndex, DirectedEdgeIndex, IndexMode, VertexIndex};
 
+#[allow(unused_imports)]
+pub use any::*;
+#[allow(unused_imports)]
+pub use binary::*;
+#[allow(unused_imports)]
+pub use builder::*;
+#[allow(unused_imports)]
+pub use dictionary::*;
+#[allow(unused_imports)]
+pub use extension::*;
 #[allow(unused_imports)]
 pub use list::*;
 #[allow(unused_imports)]
 pub use resolution::*;
 #[allow(unused_imports)]
+pub use unique::*;
+#[allow(unused_imports)]
+pub use validation::*;
+#[allow(unused_imports)]
 pub use validity::*;
 
 use crate::error::Error;
 
+mod any;
+mod binary;
+mod builder;
 mod cell;
+mod compute;
+mod dictionary;
 mod directededge;
+mod extension;
+mod from_dyn;
 pub mod from_geo;
 #[cfg(feature = "geoarrow")]
 pub mod from_geoarrow;
+mod int64;
 mod list;
 mod resolution;
 pub mod to_geo;
 #[cfg(feature = "geoarrow")]
 pub mod to_geoarrow;
+mod unique;
+mod validation;
 mod validity;
 mod vertex;
 
 pub trait H3IndexArrayValue: Into<u64> + TryFrom<u64> + Clone {
+    /// Name of the arrow extension type used to tag columns of this index type.
+    const EXTENSION_NAME: &'static str;
+
+    /// The H3 index mode of this index type.
+    const INDEX_MODE: IndexMode;
+
     fn transmute_from_u64(value: u64) -> Self;
 }
 
 impl H3IndexArrayValue for CellIndex {
+    const EXTENSION_NAME: &'static str = "h3.cell";
+    const INDEX_MODE: IndexMode = IndexMode::Cell;
+
     fn transmute_from_u64(value: u64) -> Self {
         unsafe { transmute::<u64, Self>(value) }
     }
 }
 
 impl H3IndexArrayValue for VertexIndex {
+    const EXTENSION_NAME: &'static str = "h3.vertex";
+    const INDEX_MODE: IndexMode = IndexMode::Vertex;
+
     fn transmute_from_u64(value: u64) -> Self {
         unsafe { transmute::<u64, Self>(value) }
     }
 }
 
 impl H3IndexArrayValue for DirectedEdgeIndex {
+    const EXTENSION_NAME: &'static str = "h3.directededge";
+    const INDEX_MODE: IndexMode = IndexMode::DirectedEdge;
+
     fn transmute_from_u64(value: u64) -> Self {
         unsafe { transmute::<u64, Self>(value) }
     }
@@ -87,6 +128,15 @@ where
         }
     }
 
+    /// Wrap `primitive_array` without validating its values. To be used with values derived from
+    /// already validated arrays.
+    pub(crate) fn from_primitive_array_unvalidated(primitive_array: UInt64Array) -> Self {
+        Self {
+            h3index_phantom: Default::default(),
+            primitive_array,
+        }
+    }
+
     pub fn primitive_array(&self) -> &UInt64Array {
         &self.primitive_array
     }
@@ -108,7 +158,7 @@ where
 
     /// Returns an iterator over the values and validity as Option.
     #[allow(clippy::type_complexity)]
-    pub fn iter(&self) -> PrimitiveArrayH3IndexIter<IX> {
+    pub fn iter(&self) -> PrimitiveArrayH3IndexIter<'_, IX> {
         // as the array contents have been validated upon construction, we just transmute to the h3o type
         PrimitiveArrayH3IndexIter {
             primitive_array_iter: self.primitive_array.iter(),
@@ -126,24 +176,34 @@ where
             None
         }
     }
-}
 
-pub type CellIndexArray = H3Array<CellIndex>;
-pub type VertexIndexArray = H3Array<VertexIndex>;
-pub type DirectedEdgeIndexArray = H3Array<DirectedEdgeIndex>;
+    /// Convert into an [`ArrayRef`] to be used as a column of a `RecordBatch`.
+    pub fn into_array_ref(self) -> ArrayRef {
+        Arc::new(self.primitive_array)
+    }
 
-/// Conversion corresponding to `From` with the difference that the validity mask
-/// is set accordingly to the validity to the contained values.
-pub trait FromWithValidity<T> {
-    fn from_with_validity(value: T) -> Self;
+    /// Build a [`Field`] matching the data type of this array.
+    ///
+    /// The field is always nullable, independent of the contained values, so arrays of the
+    /// same column always result in the same schema. See `extension_field` for a field which
+    /// is additionally tagged with the extension type of the H3 indexes.
+    pub fn to_field(&self, name: &str) -> Field {
+        Field::new(name, DataType::UInt64, true)
+    }
 }
 
-/// Conversion corresponding to `FromIterator` with the difference that the validity mask
-/// is set accordingly to the validity to the contained values.
-pub trait FromIteratorWithValidity<A: Sized> {
-    fn from_iter_with_validity<T: IntoIterator<Item = A>>(iter: T) -> Self;
+/// Ensure arrays to be processed pairwise have the same length.
+pub(crate) fn check_same_length(left: usize, right: usize) -> Result<(), Error> {
+    if left != right {
+        return Err(Error::LengthMismatch(left, right));
+    }
+    Ok(())
 }
 
+pub type CellIndexArray = H3Array<CellIndex>;
+pub type VertexIndexArray = H3Array<VertexIndex>;
+pub type DirectedEdgeIndexArray = H3Array<DirectedEdgeIndex>;
+
 impl<IX> TryFrom<UInt64Array> for H3Array<IX>
 where
     IX: H3IndexArrayValue + TryFrom<u64>,
@@ -152,10 +212,11 @@ where
     type Error = Error;
 
     fn try_from(value: UInt64Array) -> Result<Self, Self::Error> {
-        // validate the contained h3 cells
+        // validate the contained h3 cells. Null positions are preserved as such and are not
+        // validated. See `FromWithValidationReport` for a validation reporting all invalid values.
         value
             .iter()
-            .flatten() // TODO: should this really flatten or preserve unset positions?
+            .flatten()
             .try_for_each(|h3index| IX::try_from(h3index).map(|_| ()))?;
         Ok(H3Array {
             primitive_array: value,
@@ -235,6 +296,12 @@ impl<IX> From<H3Array<IX>> for UInt64Array {
     }
 }
 
+impl<IX> From<H3Array<IX>> for ArrayRef {
+    fn from(v: H3Array<IX>) -> Self {
+        Arc::new(v.primitive_array)
+    }
+}
+
 impl<IX> FromIteratorWithValidity<u64> for H3Array<IX>
 where
     IX: H3IndexArrayValue,
@@ -263,30 +330,3 @@ where
         }
     }
 }
-
-impl<IX> FromWithValidity<Vec<u64>> for H3Array<IX>
-where
-    IX: H3IndexArrayValue,
-{
-    fn from_with_validity(value: Vec<u64>) -> Self {
-        Self::from_iter_with_validity(value)
-    }
-}
-
-impl<IX> FromWithValidity<Vec<Option<u64>>> for H3Array<IX>
-where
-    IX: H3IndexArrayValue,
-{
-    fn from_with_validity(value: Vec<Option<u64>>) -> Self {
-        Self::from_iter_with_validity(value)
-    }
-}
-
-impl<IX> FromWithValidity<UInt64Array> for H3Array<IX>
-where
-    IX: H3IndexArrayValue,
-{
-    fn from_with_validity(value: UInt64Array) -> Self {
-        Self::from_iter_with_validity(value.iter())
-    }
-}
@@ -1,8 +1,9 @@
 use crate::array::{
-    CellIndexArray, DirectedEdgeIndexArray, H3ListArray, PrimitiveArrayH3IndexIter,
-    VertexIndexArray,
+    CellIndexArray, DirectedEdgeIndexArray, H3DictionaryArray, H3ListArray,
+    PrimitiveArrayH3IndexIter, VertexIndexArray,
 };
 use crate::error::Error;
+use arrow::datatypes::{ArrowDictionaryKeyType, ArrowNativeType};
 use geo::CoordsIter;
 use geo_types::{Coord, Line, LineString, MultiPoint, MultiPolygon, Point, Polygon};
 use h3o::geom::ToGeo;
@@ -52,6 +53,23 @@ where
     }
 }
 
+impl<K> ToPolygons for H3DictionaryArray<CellIndex, K>
+where
+    K: ArrowDictionaryKeyType,
+{
+    type Error = Infallible;
+
+    /// Each distinct cell of the dictionary is only converted once.
+    fn to_polygons(&self, use_degrees: bool) -> Result<Vec<Option<Polygon>>, Self::Error> {
+        let polygons = self.values().to_polygons(use_degrees)?;
+        Ok(self
+            .keys()
+            .iter()
+            .map(|key| key.and_then(|key| polygons[key.as_usize()].clone()))
+            .collect())
+    }
+}
+
 pub trait IterPoints {
     type Error;
 
@@ -187,14 +205,8 @@ impl ToMultiPolygons for H3ListArray<CellIndex> {
     fn to_multipolygons(&self, use_degrees: bool) -> Result<Self::Output, Self::Error> {
         self.iter_arrays()
             .map(|opt| {
-                opt.map(|res| {
-                    res.and_then(|array| {
-                        array
-                            .to_multipolygons(use_degrees)
-                            .map_err(Self::Error::from)
-                    })
-                })
-                .transpose()
+                opt.map(|res| res.and_then(|array| array.to_multipolygons(use_degrees)))
+                    .transpose()
             })
             .collect()
     }
@@ -1,12 +1,15 @@
 use crate::array::to_geo::{
     IterLines, IterPoints, IterPolygons, ToLineStrings, ToPoints, ToPolygons,
 };
-use crate::array::{H3Array, H3IndexArrayValue};
-use arrow::array::{Array, OffsetSizeTrait};
+use crate::array::{H3Array, H3DictionaryArray, H3IndexArrayValue};
+use crate::error::Error;
+use arrow::array::{Array, AsArray, OffsetSizeTrait};
+use arrow::datatypes::ArrowDictionaryKeyType;
 use geo_types::LineString;
 use geoarrow::array::{
     LineStringArray, PointArray, PolygonArray, WKBArray, WKBBuilder, WKBCapacity,
 };
+use h3o::CellIndex;
 
 pub trait ToGeoArrowPolygons {
     type Error;
@@ -118,6 +121,26 @@ where
     }
 }
 
+impl<K> ToWKBPolygons for H3DictionaryArray<CellIndex, K>
+where
+    K: ArrowDictionaryKeyType,
+{
+    type Error = Error;
+
+    /// Each distinct cell of the dictionary is only converted once.
+    fn to_wkb_polygons<O: OffsetSizeTrait>(
+        &self,
+        use_degrees: bool,
+    ) -> Result<WKBArray<O>, Self::Error> {
+        let values = match self.values().to_wkb_polygons::<O>(use_degrees) {
+            Ok(wkbarray) => wkbarray.into_inner(),
+            Err(never) => match never {},
+        };
+        let wkbs = arrow::compute::take(&values, self.keys(), None)?;
+        Ok(wkbs.as_binary::<O>().clone().into())
+    }
+}
+
 pub trait ToWKBLineStrings {
     type Error;
     fn to_wkb_linestrings<O: OffsetSizeTrait>(
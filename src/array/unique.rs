@@ -0,0 +1,134 @@
+use ahash::{HashMap, HashMapExt, HashSet, HashSetExt};
+use arrow::array::UInt64Array;
+
+use crate::array::{H3Array, H3IndexArrayValue};
+
+pub struct ValueCounts<IX> {
+    /// the distinct values, sorted ascending
+    pub values: H3Array<IX>,
+
+    /// the number of occurrences of each of the `values`
+    pub counts: UInt64Array,
+}
+
+impl<IX> H3Array<IX>
+where
+    IX: H3IndexArrayValue,
+{
+    /// The distinct values in the order of their first occurrence.
+    ///
+    /// Null values are omitted.
+    pub fn unique(&self) -> Self {
+        let mut seen = HashSet::with_capacity(self.len());
+        let values: Vec<u64> = self
+            .primitive_array
+            .iter()
+            .flatten()
+            .filter(|h3index| seen.insert(*h3index))
+            .collect();
+        Self::from_primitive_array_unvalidated(values.into())
+    }
+
+    /// The distinct values, sorted ascending, together with the number of their occurrences.
+    ///
+    /// Null values are omitted.
+    pub fn value_counts(&self) -> ValueCounts<IX> {
+        let mut counts: Vec<_> = count_values(&self.primitive_array).into_iter().collect();
+        counts.sort_unstable_by_key(|(h3index, _)| *h3index);
+
+        let (values, counts): (Vec<u64>, Vec<u64>) = counts.into_iter().unzip();
+        ValueCounts {
+            values: Self::from_primitive_array_unvalidated(values.into()),
+            counts: counts.into(),
+        }
+    }
+
+    /// The distinct values, sorted ascending.
+    ///
+    /// Null values are omitted.
+    pub fn dedup_sorted(&self) -> Self {
+        let mut values: Vec<u64> = self.primitive_array.iter().flatten().collect();
+
+        #[cfg(feature = "rayon")]
+        {
+            use rayon::slice::ParallelSliceMut;
+            values.par_sort_unstable();
+        }
+
+        #[cfg(not(feature = "rayon"))]
+        values.sort_unstable();
+
+        values.dedup();
+        Self::from_primitive_array_unvalidated(values.into())
+    }
+}
+
+#[cfg(not(feature = "rayon"))]
+fn count_values(array: &UInt64Array) -> HashMap<u64, u64> {
+    let mut counts = HashMap::new();
+    for h3index in array.iter().flatten() {
+        *counts.entry(h3index).or_insert(0) += 1;
+    }
+    counts
+}
+
+#[cfg(feature = "rayon")]
+fn count_values(array: &UInt64Array) -> HashMap<u64, u64> {
+    use arrow::array::Array;
+    use rayon::prelude::{IntoParallelIterator, ParallelIterator};
+
+    (0..array.len())
+        .into_par_iter()
+        .fold(HashMap::new, |mut counts, i| {
+            if array.is_valid(i) {
+                *counts.entry(array.value(i)).or_insert(0) += 1;
+            }
+            counts
+        })
+        .reduce(HashMap::new, |a, b| {
+            // merge the smaller map into the larger one
+            let (mut large, small) = if a.len() > b.len() { (a, b) } else { (b, a) };
+            for (h3index, count) in small {
+                *large.entry(h3index).or_insert(0) += count;
+            }
+            large
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use h3o::{LatLng, Resolution};
+
+    use crate::array::CellIndexArray;
+
+    #[test]
+    fn unique_and_counts() {
+        let cell_a = LatLng::new(23.4, 12.4).unwrap().to_cell(Resolution::Five);
+        let cell_b = LatLng::new(12.3, 0.5).unwrap().to_cell(Resolution::Nine);
+        let arr: CellIndexArray =
+            vec![Some(cell_b), Some(cell_a), None, Some(cell_b), Some(cell_b)].into();
+
+        let unique = arr.unique();
+        assert_eq!(
+            unique.iter().collect::<Vec<_>>(),
+            vec![Some(cell_b), Some(cell_a)]
+        );
+
+        let mut expected = vec![cell_a, cell_b];
+        expected.sort_unstable();
+        let deduped = arr.dedup_sorted();
+        assert_eq!(deduped.iter().flatten().collect::<Vec<_>>(), expected);
+
+        let value_counts = arr.value_counts();
+        assert_eq!(
+            value_counts.values.iter().flatten().collect::<Vec<_>>(),
+            expected
+        );
+        let count_of = |cell| {
+            let pos = expected.iter().position(|c| *c == cell).unwrap();
+            value_counts.counts.value(pos)
+        };
+        assert_eq!(count_of(cell_a), 1);
+        assert_eq!(count_of(cell_b), 3);
+    }
+}
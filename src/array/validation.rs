@@ -0,0 +1,233 @@
+use std::fmt;
+
+use arrow::array::UInt64Array;
+use h3o::{BaseCell, IndexMode};
+
+use crate::array::any::{MODE_MASK, MODE_OFFSET};
+use crate::array::{H3Array, H3IndexArrayValue};
+
+/// The reason why a value is not a valid H3 index.
+///
+/// The resolution of an index is encoded using 4 bits and is therefore always valid.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum InvalidReason {
+    /// Reserved bits of the index are set.
+    ReservedBits,
+
+    /// The index mode does not match the expected index type.
+    InvalidMode,
+
+    /// The base cell number is out of range.
+    InvalidBaseCell,
+
+    /// A digit up to the resolution of the index is set to the unused digit `7`.
+    UnexpectedUnusedDigit,
+
+    /// A digit beyond the resolution of the index is not set to the unused digit `7`.
+    UnusedDigitSet,
+
+    /// A pentagon index containing the deleted k-axes subsequence.
+    DeletedSubsequence,
+
+    /// Any other reason, like an invalid edge or vertex of the owning cell.
+    Other,
+}
+
+impl fmt::Display for InvalidReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::ReservedBits => "reserved bits set",
+            Self::InvalidMode => "invalid index mode",
+            Self::InvalidBaseCell => "invalid base cell",
+            Self::UnexpectedUnusedDigit => "unexpected unused digit",
+            Self::UnusedDigitSet => "unused digit set",
+            Self::DeletedSubsequence => "pentagon with deleted subsequence",
+            Self::Other => "invalid index",
+        })
+    }
+}
+
+/// An invalid value found during validation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct InvalidValue {
+    /// Position of the value in the validated array.
+    pub position: usize,
+    pub value: u64,
+    pub reason: InvalidReason,
+}
+
+/// Report on all invalid values found during the validation of an array.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ValidationReport {
+    pub invalid_values: Vec<InvalidValue>,
+}
+
+impl ValidationReport {
+    /// true when no invalid values have been found.
+    pub fn is_valid(&self) -> bool {
+        self.invalid_values.is_empty()
+    }
+
+    pub fn invalid_count(&self) -> usize {
+        self.invalid_values.len()
+    }
+
+    /// Positions of the invalid values in the validated array.
+    pub fn positions(&self) -> impl Iterator<Item = usize> + '_ {
+        self.invalid_values.iter().map(|iv| iv.position)
+    }
+
+    /// Number of invalid values having the given `reason`.
+    pub fn count_by_reason(&self, reason: InvalidReason) -> usize {
+        self.invalid_values
+            .iter()
+            .filter(|iv| iv.reason == reason)
+            .count()
+    }
+}
+
+impl fmt::Display for ValidationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} invalid values", self.invalid_count())?;
+        if let Some(first) = self.invalid_values.first() {
+            write!(
+                f,
+                " (first at position {}: {:#x}, {})",
+                first.position, first.value, first.reason
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Conversion corresponding to [`FromWithValidity`](crate::array::FromWithValidity) with the
+/// difference that each value set to null for being invalid is listed in a [`ValidationReport`].
+pub trait FromWithValidationReport<T>: Sized {
+    fn from_with_validation_report(value: T) -> (Self, ValidationReport);
+}
+
+impl<IX> FromWithValidationReport<UInt64Array> for H3Array<IX>
+where
+    IX: H3IndexArrayValue,
+{
+    fn from_with_validation_report(value: UInt64Array) -> (Self, ValidationReport) {
+        let mut report = ValidationReport::default();
+        let array = value
+            .iter()
+            .enumerate()
+            .map(|(position, h3index)| {
+                h3index.and_then(|h3index| match IX::try_from(h3index) {
+                    Ok(ix) => Some(ix),
+                    Err(_) => {
+                        report.invalid_values.push(InvalidValue {
+                            position,
+                            value: h3index,
+                            reason: invalid_reason(h3index, IX::INDEX_MODE),
+                        });
+                        None
+                    }
+                })
+            })
+            .collect();
+        (array, report)
+    }
+}
+
+const BASE_CELL_OFFSET: usize = 45;
+const RESOLUTION_OFFSET: usize = 52;
+const DIGIT_BITSIZE: usize = 3;
+const UNUSED_DIGIT: u64 = 0b111;
+const MAX_RESOLUTION: u64 = 15;
+
+/// Determine why `value` is not a valid index of the given `index_mode`.
+fn invalid_reason(value: u64, index_mode: IndexMode) -> InvalidReason {
+    if value >> 63 != 0 {
+        return InvalidReason::ReservedBits;
+    }
+    if (value & MODE_MASK) >> MODE_OFFSET != u64::from(u8::from(index_mode)) {
+        return InvalidReason::InvalidMode;
+    }
+    let base_cell_number = ((value >> BASE_CELL_OFFSET) & 0b111_1111) as u8;
+    let Ok(base_cell) = BaseCell::try_from(base_cell_number) else {
+        return InvalidReason::InvalidBaseCell;
+    };
+
+    let resolution = (value >> RESOLUTION_OFFSET) & 0b1111;
+    let digit = |r: u64| (value >> ((MAX_RESOLUTION - r) as usize * DIGIT_BITSIZE)) & 0b111;
+    for r in 1..=MAX_RESOLUTION {
+        if r <= resolution && digit(r) == UNUSED_DIGIT {
+            return InvalidReason::UnexpectedUnusedDigit;
+        }
+        if r > resolution && digit(r) != UNUSED_DIGIT {
+            return InvalidReason::UnusedDigitSet;
+        }
+    }
+
+    // the first non-center digit of a pentagon must not be the k-axes digit
+    if base_cell.is_pentagon() && (1..=resolution).map(digit).find(|d| *d != 0) == Some(1) {
+        return InvalidReason::DeletedSubsequence;
+    }
+
+    // cells do not use the edge/vertex bits
+    if index_mode == IndexMode::Cell && (value >> 56) & 0b111 != 0 {
+        return InvalidReason::ReservedBits;
+    }
+    InvalidReason::Other
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow::array::UInt64Array;
+    use h3o::{LatLng, Resolution};
+
+    use crate::array::{CellIndexArray, FromWithValidationReport, InvalidReason};
+
+    #[test]
+    fn report_invalid_values() {
+        let cell = u64::from(LatLng::new(23.4, 12.4).unwrap().to_cell(Resolution::Five));
+        let vertex = u64::from(
+            LatLng::new(23.4, 12.4)
+                .unwrap()
+                .to_cell(Resolution::Five)
+                .vertexes()
+                .next()
+                .unwrap(),
+        );
+
+        let (cells, report) = CellIndexArray::from_with_validation_report(UInt64Array::from(vec![
+            Some(cell),
+            None,
+            Some(vertex),
+            Some(cell | (127 << 45)),
+            Some(cell & !0b111),
+            Some(cell | (1 << 63)),
+        ]));
+
+        assert_eq!(cells.len(), 6);
+        assert_eq!(cells.iter().flatten().count(), 1);
+        assert_eq!(report.invalid_count(), 4);
+        assert_eq!(report.positions().collect::<Vec<_>>(), vec![2, 3, 4, 5]);
+        assert_eq!(
+            report
+                .invalid_values
+                .iter()
+                .map(|iv| iv.reason)
+                .collect::<Vec<_>>(),
+            vec![
+                InvalidReason::InvalidMode,
+                InvalidReason::InvalidBaseCell,
+                InvalidReason::UnusedDigitSet,
+                InvalidReason::ReservedBits
+            ]
+        );
+    }
+
+    #[test]
+    fn report_valid() {
+        let cell = u64::from(LatLng::new(23.4, 12.4).unwrap().to_cell(Resolution::Five));
+        let (cells, report) =
+            CellIndexArray::from_with_validation_report(UInt64Array::from(vec![cell]));
+        assert_eq!(cells.len(), 1);
+        assert!(report.is_valid());
+    }
+}
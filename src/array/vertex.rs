@@ -1,7 +1,46 @@
+use h3o::Vertex;
+
 use crate::array::{CellIndexArray, VertexIndexArray};
 
 impl VertexIndexArray {
+    /// The vertex numbered `vertex` of each of the `cells`.
+    ///
+    /// The value is null when the cell does not have that vertex, like the sixth vertex
+    /// of a pentagon.
+    pub fn from_cells(cells: &CellIndexArray, vertex: Vertex) -> Self {
+        cells
+            .iter()
+            .map(|cell| cell.and_then(|cell| cell.vertex(vertex)))
+            .collect()
+    }
+
     pub fn owner(&self) -> CellIndexArray {
         self.iter().map(|vx| vx.map(|vx| vx.owner())).collect()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use h3o::{LatLng, Resolution, Vertex};
+
+    use crate::array::{CellIndexArray, VertexIndexArray};
+
+    #[test]
+    fn from_cells() {
+        let pentagon = Resolution::Five.pentagons().next().unwrap();
+        let hexagon = LatLng::new(12.3, 0.5).unwrap().to_cell(Resolution::Four);
+        let cells: CellIndexArray = vec![Some(pentagon), None, Some(hexagon)].into();
+
+        let vertexes = VertexIndexArray::from_cells(&cells, Vertex::try_from(5).unwrap());
+        assert_eq!(vertexes.len(), 3);
+        assert_eq!(vertexes.get(0), None);
+        assert_eq!(vertexes.get(1), None);
+        assert_eq!(
+            vertexes.get(2),
+            hexagon.vertex(Vertex::try_from(5).unwrap())
+        );
+
+        let vertexes = VertexIndexArray::from_cells(&cells, Vertex::try_from(0).unwrap());
+        assert!(vertexes.get(0).is_some());
+    }
+}
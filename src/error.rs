@@ -30,6 +30,30 @@ pub enum Error {
     #[error("not a UintArray")]
     NotAUint64Array,
 
+    #[error("unsupported data type: {0}")]
+    UnsupportedDataType(arrow::datatypes::DataType),
+
+    #[error("expected arrow extension type {expected}, found {found:?}")]
+    ExtensionTypeMismatch {
+        expected: &'static str,
+        found: Option<String>,
+    },
+
+    #[error("unable to detect a single H3 index mode ({0})")]
+    AmbiguousIndexMode(crate::array::IndexModeCounts),
+
+    #[error("arrays of different lengths: {0} and {1}")]
+    LengthMismatch(usize, usize),
+
+    #[error("cells of resolution {cell_resolution} are finer than the requested resolution {resolution}")]
+    CellFinerThanResolution {
+        cell_resolution: h3o::Resolution,
+        resolution: h3o::Resolution,
+    },
+
+    #[error("invalid sigma of the gaussian smoothing kernel: {0}")]
+    InvalidKernelSigma(f64),
+
     #[error("non-parsable CellIndex")]
     NonParsableCellIndex,
 
@@ -5,6 +5,7 @@ pub mod algorithm;
 pub mod array;
 pub mod error;
 pub mod export;
+pub mod record_batch;
 
 #[cfg(feature = "spatial_index")]
 pub mod spatial_index;
@@ -0,0 +1,244 @@
+//! H3 operations on the columns of a [`RecordBatch`], addressed by column name.
+
+use std::sync::Arc;
+
+use arrow::array::{
+    Array, ArrayRef, AsArray, GenericListArray, OffsetSizeTrait, RecordBatch, UInt64Array,
+};
+use arrow::compute;
+use arrow::datatypes::{DataType, Field, Schema};
+use h3o::Resolution;
+
+use crate::algorithm::ToGenericStringArray;
+use crate::array::{CellIndexArray, H3Array, H3IndexArrayValue, H3ListArray, RowIndexedH3Array};
+use crate::error::Error;
+
+/// Each method applies an operation to a column of the batch and returns a new batch with the
+/// result appended as a new column.
+///
+/// Input columns are converted using [`H3Array::try_from_dyn`], so all data types supported
+/// by it can be used.
+pub trait RecordBatchH3Ext: Sized {
+    /// Append the parent cells at `resolution` of the cells in `column`.
+    fn with_h3_parent(
+        &self,
+        column: &str,
+        resolution: Resolution,
+        output_name: &str,
+    ) -> Result<Self, Error>;
+
+    /// Append the string representation of the cells in `column`.
+    fn with_h3_strings(&self, column: &str, output_name: &str) -> Result<Self, Error>;
+
+    /// Append the polygons of the cells in `column` as WKB using degree coordinates.
+    #[cfg(feature = "geoarrow")]
+    fn with_h3_wkb_polygons(&self, column: &str, output_name: &str) -> Result<Self, Error>;
+
+    /// Replace the list column `column` by its values while repeating the values of all other
+    /// columns for each element of the list. Rows with null or empty lists are dropped.
+    fn explode_h3_list<IX>(&self, column: &str) -> Result<Self, Error>
+    where
+        IX: H3IndexArrayValue,
+        H3Array<IX>: TryFrom<UInt64Array, Error = Error>;
+}
+
+impl RecordBatchH3Ext for RecordBatch {
+    fn with_h3_parent(
+        &self,
+        column: &str,
+        resolution: Resolution,
+        output_name: &str,
+    ) -> Result<Self, Error> {
+        let parents = cells_of_column(self, column)?.parent(resolution);
+        let field = parents.to_field(output_name);
+        append_column(self, field, parents.into())
+    }
+
+    fn with_h3_strings(&self, column: &str, output_name: &str) -> Result<Self, Error> {
+        let strings =
+            ToGenericStringArray::<i32>::to_genericstringarray(&cells_of_column(self, column)?)?;
+        let field = Field::new(output_name, DataType::Utf8, true);
+        append_column(self, field, Arc::new(strings))
+    }
+
+    #[cfg(feature = "geoarrow")]
+    fn with_h3_wkb_polygons(&self, column: &str, output_name: &str) -> Result<Self, Error> {
+        use crate::array::to_geoarrow::ToWKBPolygons;
+        use geoarrow::GeometryArrayTrait;
+
+        let wkbarray = match cells_of_column(self, column)?.to_wkb_polygons::<i32>(true) {
+            Ok(wkbarray) => wkbarray,
+            Err(never) => match never {},
+        };
+        let field = wkbarray
+            .extension_field()
+            .as_ref()
+            .clone()
+            .with_name(output_name);
+        append_column(self, field, Arc::new(wkbarray.into_inner()))
+    }
+
+    fn explode_h3_list<IX>(&self, column: &str) -> Result<Self, Error>
+    where
+        IX: H3IndexArrayValue,
+        H3Array<IX>: TryFrom<UInt64Array, Error = Error>,
+    {
+        let column_i = self.schema().index_of(column)?;
+        let list_array = self.column(column_i);
+        let exploded = match list_array.data_type() {
+            DataType::List(_) => explode::<IX, i32>(list_array.as_list::<i32>())?,
+            DataType::LargeList(_) => explode::<IX, i64>(list_array.as_list::<i64>())?,
+            data_type => return Err(Error::UnsupportedDataType(data_type.clone())),
+        };
+
+        let schema = self.schema();
+        let mut fields = Vec::with_capacity(self.num_columns());
+        let mut columns = Vec::with_capacity(self.num_columns());
+        for (i, (field, array)) in schema.fields().iter().zip(self.columns()).enumerate() {
+            if i == column_i {
+                fields.push(
+                    exploded
+                        .values
+                        .to_field(field.name())
+                        .with_metadata(field.metadata().clone()),
+                );
+                columns.push(exploded.values.clone().into_array_ref());
+            } else {
+                fields.push(field.as_ref().clone());
+                columns.push(compute::take(array.as_ref(), &exploded.row_indices, None)?);
+            }
+        }
+
+        Ok(RecordBatch::try_new(
+            Arc::new(Schema::new_with_metadata(fields, schema.metadata().clone())),
+            columns,
+        )?)
+    }
+}
+
+fn cells_of_column(batch: &RecordBatch, column: &str) -> Result<CellIndexArray, Error> {
+    let column_i = batch.schema().index_of(column)?;
+    CellIndexArray::try_from_dyn(batch.column(column_i).as_ref())
+}
+
+fn explode<IX, O>(list_array: &GenericListArray<O>) -> Result<RowIndexedH3Array<IX>, Error>
+where
+    IX: H3IndexArrayValue,
+    O: OffsetSizeTrait,
+    H3Array<IX>: TryFrom<UInt64Array, Error = Error>,
+{
+    H3ListArray::<IX, O>::try_from(list_array.clone())?.explode()
+}
+
+fn append_column(batch: &RecordBatch, field: Field, array: ArrayRef) -> Result<RecordBatch, Error> {
+    let schema = batch.schema();
+    let mut fields: Vec<Field> = schema
+        .fields()
+        .iter()
+        .map(|field| field.as_ref().clone())
+        .collect();
+    fields.push(field);
+
+    let mut columns = batch.columns().to_vec();
+    columns.push(array);
+
+    Ok(RecordBatch::try_new(
+        Arc::new(Schema::new_with_metadata(fields, schema.metadata().clone())),
+        columns,
+    )?)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use arrow::array::{Array, AsArray, RecordBatch, UInt32Array};
+    use arrow::datatypes::{Field, Schema, UInt32Type};
+    use h3o::{CellIndex, LatLng, Resolution};
+
+    use crate::algorithm::GridOp;
+    use crate::array::{CellIndexArray, H3ListArray};
+    use crate::record_batch::RecordBatchH3Ext;
+
+    fn some_cells() -> CellIndexArray {
+        vec![
+            Some(LatLng::new(23.4, 12.4).unwrap().to_cell(Resolution::Nine)),
+            None,
+            Some(LatLng::new(12.3, 0.5).unwrap().to_cell(Resolution::Nine)),
+        ]
+        .into()
+    }
+
+    fn some_batch() -> RecordBatch {
+        let cells = some_cells();
+        let ids = UInt32Array::from(vec![1, 2, 3]);
+        RecordBatch::try_new(
+            Arc::new(Schema::new(vec![
+                cells.to_field("cell"),
+                Field::new("id", ids.data_type().clone(), false),
+            ])),
+            vec![cells.into_array_ref(), Arc::new(ids)],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn with_parent_and_strings() {
+        let batch = some_batch()
+            .with_h3_parent("cell", Resolution::Seven, "parent")
+            .unwrap()
+            .with_h3_strings("parent", "parent_str")
+            .unwrap();
+        assert_eq!(batch.num_columns(), 4);
+
+        let parents = CellIndexArray::try_from_dyn(batch.column(2).as_ref()).unwrap();
+        assert!(parents == some_cells().parent(Resolution::Seven));
+
+        let strings = batch.column(3).as_string::<i32>();
+        assert_eq!(
+            strings.value(0),
+            parents.get(0).unwrap().to_string().as_str()
+        );
+        assert!(strings.is_null(1));
+
+        assert!(some_batch()
+            .with_h3_parent("missing", Resolution::Seven, "parent")
+            .is_err());
+    }
+
+    #[cfg(feature = "geoarrow")]
+    #[test]
+    fn with_wkb_polygons() {
+        let batch = some_batch().with_h3_wkb_polygons("cell", "geom").unwrap();
+        let schema = batch.schema();
+        let field = schema.field_with_name("geom").unwrap();
+        assert_eq!(
+            field
+                .metadata()
+                .get("ARROW:extension:name")
+                .map(String::as_str),
+            Some("geoarrow.wkb")
+        );
+        assert!(batch.column(2).is_null(1));
+    }
+
+    #[test]
+    fn explode_list() {
+        let disks: H3ListArray<CellIndex> = some_cells().grid_disk(1).unwrap();
+        let ids = UInt32Array::from(vec![1, 2, 3]);
+        let batch = RecordBatch::try_from_iter(vec![
+            ("disk", Arc::new(disks.listarray().clone()) as _),
+            ("id", Arc::new(ids) as _),
+        ])
+        .unwrap();
+
+        let exploded = batch.explode_h3_list::<CellIndex>("disk").unwrap();
+        assert_eq!(exploded.num_rows(), 14);
+        let exploded_ids = exploded.column(1).as_primitive::<UInt32Type>();
+        assert_eq!(exploded_ids.value(0), 1);
+        assert_eq!(exploded_ids.value(13), 3);
+
+        let cells = CellIndexArray::try_from_dyn(exploded.column(0).as_ref()).unwrap();
+        assert_eq!(cells.len(), 14);
+    }
+}
@@ -6,7 +6,7 @@ use h3o::{CellIndex, DirectedEdgeIndex, LatLng, VertexIndex};
 use rstar::primitives::{GeomWithData, Rectangle};
 use rstar::{RTree, AABB};
 
-use crate::array::{H3Array, H3IndexArrayValue};
+use crate::array::{AnyH3Array, H3Array, H3IndexArrayValue};
 
 pub trait RectIndexable {
     fn spatial_index_rect(&self) -> Option<Rect>;
@@ -166,6 +166,52 @@ where
     }
 }
 
+/// Spatial index over an [`AnyH3Array`].
+pub enum AnySpatialIndex {
+    Cell(SpatialIndex<CellIndex>),
+    DirectedEdge(SpatialIndex<DirectedEdgeIndex>),
+    Vertex(SpatialIndex<VertexIndex>),
+}
+
+macro_rules! dispatch {
+    ($any:expr, $index:ident => $expr:expr) => {
+        match $any {
+            AnySpatialIndex::Cell($index) => $expr,
+            AnySpatialIndex::DirectedEdge($index) => $expr,
+            AnySpatialIndex::Vertex($index) => $expr,
+        }
+    };
+}
+
+impl AnySpatialIndex {
+    pub fn intersect_envelopes(&self, rect: &Rect) -> BooleanArray {
+        dispatch!(self, index => index.intersect_envelopes(rect))
+    }
+
+    pub fn intersect_polygon(&self, poly: &Polygon) -> BooleanArray {
+        dispatch!(self, index => index.intersect_polygon(poly))
+    }
+
+    pub fn intersect_multipolygon(&self, mpoly: &MultiPolygon) -> BooleanArray {
+        dispatch!(self, index => index.intersect_multipolygon(mpoly))
+    }
+
+    /// The envelope of the indexed elements is with `distance` of the given [Coord] `coord`.
+    pub fn envelopes_within_distance(&self, coord: Coord, distance: f64) -> BooleanArray {
+        dispatch!(self, index => index.envelopes_within_distance(coord, distance))
+    }
+}
+
+impl AnyH3Array {
+    pub fn spatial_index(&self) -> AnySpatialIndex {
+        match self {
+            Self::Cell(array) => AnySpatialIndex::Cell(array.spatial_index()),
+            Self::DirectedEdge(array) => AnySpatialIndex::DirectedEdge(array.spatial_index()),
+            Self::Vertex(array) => AnySpatialIndex::Vertex(array.spatial_index()),
+        }
+    }
+}
+
 pub(crate) fn negative_mask(size: usize) -> BooleanBufferBuilder {
     let mut builder = BooleanBufferBuilder::new(size);
     builder.append_n(size, false);
@@ -178,7 +224,7 @@ mod tests {
     use geo_types::{coord, polygon};
     use h3o::{LatLng, Resolution};
 
-    use crate::array::CellIndexArray;
+    use crate::array::{AnyH3Array, CellIndexArray};
 
     #[test]
     fn cell_create_empty_index() {
@@ -212,13 +258,13 @@ mod tests {
         assert_eq!(mask.len(), 4);
 
         assert!(mask.is_valid(0));
-        assert_eq!(mask.value(0), false);
+        assert!(!mask.value(0));
 
         assert!(mask.is_valid(1));
-        assert_eq!(mask.value(1), true);
+        assert!(mask.value(1));
 
         assert!(mask.is_valid(2));
-        assert_eq!(mask.value(2), false);
+        assert!(!mask.value(2));
 
         assert!(!mask.is_valid(3));
     }
@@ -237,14 +283,25 @@ mod tests {
         assert_eq!(mask.len(), 4);
 
         assert!(mask.is_valid(0));
-        assert_eq!(mask.value(0), true);
+        assert!(mask.value(0));
 
         assert!(mask.is_valid(1));
-        assert_eq!(mask.value(1), false);
+        assert!(!mask.value(1));
 
         assert!(mask.is_valid(2));
-        assert_eq!(mask.value(2), false);
+        assert!(!mask.value(2));
 
         assert!(!mask.is_valid(3));
     }
+
+    #[test]
+    fn any_envelopes_within_distance() {
+        let idx = AnyH3Array::from(some_cell_array()).spatial_index();
+        let mask = idx.envelopes_within_distance((-60.0, -60.0).into(), 2.0);
+
+        assert_eq!(mask.len(), 4);
+        assert!(!mask.value(0));
+        assert!(mask.value(1));
+        assert!(!mask.is_valid(3));
+    }
 }